@@ -0,0 +1,136 @@
+//! Generates the registry of all solutions in `src/bin` that is included by `src/main.rs` (empty without the
+//! `in_process` feature), and one test per example in `data/YYYY/examples/NN.toml` that the `solution!` macro
+//! includes in each solution.
+use std::path::Path;
+use std::{env, fs};
+
+/// The manifest parser of the library, the build script only needs its table names.
+#[allow(dead_code)]
+#[path = "src/template/toml.rs"]
+mod toml;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=data");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    let data_dir = Path::new(&manifest_dir).join("data");
+
+    // solutions are named `YYYY-DD.rs`.
+    let mut bins: Vec<(String, String)> = file_names(&bin_dir)
+        .iter()
+        .filter_map(|name| name.strip_suffix(".rs")?.split_once('-'))
+        .filter(|(year, day)| is_number(year, 4) && is_number(day, 2))
+        .map(|(year, day)| (year.to_string(), day.to_string()))
+        .collect();
+
+    bins.sort();
+
+    // only the `in_process` feature compiles the solutions into the main binary, so that a day that does not compile
+    // does not break the other commands.
+    let registered: &[(String, String)] = if env::var_os("CARGO_FEATURE_IN_PROCESS").is_some() {
+        &bins
+    } else {
+        &[]
+    };
+
+    let mut registry = String::new();
+
+    for (year, day) in registered {
+        let path = bin_dir.join(format!("{year}-{day}.rs"));
+        // the tests of a solution already run as part of its own binary.
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {:?}]\nmod day_{year}_{day};\n\n",
+            path.display().to_string()
+        ));
+    }
+
+    let entries: Vec<String> = registered
+        .iter()
+        .map(|(year, day)| format!("day_{year}_{day}::SOLUTION"))
+        .collect();
+
+    registry.push_str(&format!(
+        "#[cfg(not(test))]\npub const SOLUTIONS: &[advent_of_code::template::registry::Entry] = &[{}];\n\n",
+        entries.join(", ")
+    ));
+    registry.push_str(
+        "#[cfg(test)]\npub const SOLUTIONS: &[advent_of_code::template::registry::Entry] = &[];\n",
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+
+    // the years of all solutions and of all `data/YYYY` folders.
+    let mut years: Vec<String> = file_names(&data_dir)
+        .into_iter()
+        .filter(|name| is_number(name, 4))
+        .chain(bins.iter().map(|(year, _)| year.clone()))
+        .collect();
+
+    years.sort();
+    years.dedup();
+
+    for year in &years {
+        let examples_dir = data_dir.join(year).join("examples");
+        let tests_dir = Path::new(&out_dir).join("examples").join(year);
+        fs::create_dir_all(&tests_dir).unwrap();
+
+        // every day gets a file, so that solutions without a manifest can include it as well.
+        for day in 1..=25 {
+            let path = examples_dir.join(format!("{day:02}.toml"));
+            let manifest = fs::read_to_string(&path).unwrap_or_default();
+            let tests: String = example_names(&manifest)
+                .unwrap_or_else(|e| panic!("invalid example manifest {}: {e}", path.display()))
+                .iter()
+                .map(|name| {
+                    format!(
+                        "#[test]\nfn example_{}() {{\n    advent_of_code::template::examples::check::<super::Puzzle>({name:?});\n}}\n\n",
+                        name.to_lowercase()
+                    )
+                })
+                .collect();
+            // the `solution!` macro includes the file named after its day token, e.g. `1` or `01`.
+            fs::write(tests_dir.join(format!("{day}.rs")), &tests).unwrap();
+            fs::write(tests_dir.join(format!("{day:02}.rs")), tests).unwrap();
+        }
+    }
+}
+
+fn file_names(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn is_number(s: &str, len: usize) -> bool {
+    s.len() == len && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// The names of the examples in a manifest, i.e. its tables. The library parses the examples themselves.
+/// Names become test names in lowercase, so names that only differ by case are rejected.
+fn example_names(manifest: &str) -> Result<Vec<String>, String> {
+    let names: Vec<String> = toml::parse_tables(manifest)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter_map(|table| table.name)
+        .collect();
+
+    for (i, name) in names.iter().enumerate() {
+        if let Some(other) = names[..i]
+            .iter()
+            .find(|other| other.eq_ignore_ascii_case(name))
+        {
+            return Err(format!(
+                "examples [{other}] and [{name}] only differ by case, their tests would have the same name"
+            ));
+        }
+    }
+
+    Ok(names)
+}
@@ -7,7 +7,7 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2, process_input -> Vec<Game>);
 
 #[derive(Debug, PartialEq)]
 enum Color {
@@ -23,7 +23,7 @@ struct CubeSet {
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: u32,
     cube_sets: Vec<CubeSet>,
 }
@@ -75,11 +75,10 @@ fn find_max_reveled_color(game: &Game, color: Color) -> u32 {
         .unwrap()
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(games: &[Game]) -> Option<u32> {
     let max_red = 12;
     let max_green = 13;
     let max_blue = 14;
-    let games = process_input(input);
     let games_max_reveled_sets = games.iter().map(|game| {
         let max_reveled_red = find_max_reveled_color(game, Color::Red);
         let max_reveled_green = find_max_reveled_color(game, Color::Green);
@@ -98,8 +97,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let games = process_input(input);
+pub fn part_two(games: &[Game]) -> Option<u32> {
     let games_max_reveled_sets = games.iter().map(|game| {
         let max_reveled_red = find_max_reveled_color(game, Color::Red);
         let max_reveled_green = find_max_reveled_color(game, Color::Green);
@@ -116,20 +114,3 @@ pub fn part_two(input: &str) -> Option<u32> {
         .sum();
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2286));
-    }
-}
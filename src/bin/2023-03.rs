@@ -1,6 +1,6 @@
 use std::cmp::{max, min};
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 #[derive(Debug)]
 struct Part {
@@ -11,10 +11,12 @@ struct Part {
 }
 
 impl Part {
-    fn is_connected_with_symbol(&self, board: &Vec<Vec<char>>) -> bool {
-        for i in (max(self.line as isize - 1, 0) as usize)..min(self.line + 2, board.len()) {
-            for j in (max(self.start as isize - 1, 0) as usize)..min(self.end + 1, board[0].len()) {
-                let c = board[i][j];
+    fn is_connected_with_symbol(&self, board: &[Vec<char>]) -> bool {
+        for row in
+            &board[(max(self.line as isize - 1, 0) as usize)..min(self.line + 2, board.len())]
+        {
+            for &c in &row[(max(self.start as isize - 1, 0) as usize)..min(self.end + 1, row.len())]
+            {
                 if !c.is_numeric() && c != '.' {
                     return true;
                 }
@@ -113,20 +115,3 @@ pub fn part_two(input: &str) -> Option<u32> {
         .sum();
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4361));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(467835));
-    }
-}
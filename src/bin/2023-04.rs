@@ -8,7 +8,7 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 #[derive(Debug)]
 struct Card {
@@ -82,20 +82,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     }
     Some(number_of_cards.values().sum())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(30));
-    }
-}
@@ -6,7 +6,7 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2023, 5);
 
 type SeedRange = (u64, u64);
 
@@ -163,18 +163,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(35));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
-    }
-
     #[test]
     fn test_seed_range_mapping_simple() {
         let mapping = Mapping {
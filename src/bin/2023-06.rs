@@ -7,7 +7,7 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 #[derive(Debug)]
 struct Game {
@@ -92,18 +92,6 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(288));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(71503));
-    }
-
     #[test]
     fn test_get_number_of_possible_wins_even() {
         let game = Game {
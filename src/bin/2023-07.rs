@@ -8,7 +8,7 @@ use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 use nom::IResult;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7, parse -> Vec<Position>);
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 enum Card {
@@ -28,15 +28,14 @@ enum Card {
     Joker = 1,
 }
 
-fn parse_cards(card_chars: &str, joker_instead_of_jack: bool) -> Vec<Card> {
+fn parse_cards(card_chars: &str) -> Vec<Card> {
     card_chars
         .chars()
         .map(|c| match c {
             'A' => Card::Ace,
             'K' => Card::King,
             'Q' => Card::Queen,
-            'J' if joker_instead_of_jack => Card::Joker,
-            'J' if !joker_instead_of_jack => Card::Jack,
+            'J' => Card::Jack,
             'T' => Card::Ten,
             '9' => Card::Nine,
             '8' => Card::Eight,
@@ -82,8 +81,14 @@ struct Hand {
 }
 
 impl Hand {
-    fn new(card_chars: &str, joker_instead_of_jack: bool) -> Hand {
-        let cards = parse_cards(card_chars, joker_instead_of_jack);
+    fn new(cards: &[Card], joker_instead_of_jack: bool) -> Hand {
+        let cards: Vec<Card> = cards
+            .iter()
+            .map(|c| match c {
+                Card::Jack if joker_instead_of_jack => Card::Joker,
+                c => c.clone(),
+            })
+            .collect();
         let jokers_count: u32 = cards
             .iter()
             .filter(|&c| joker_instead_of_jack && c.cmp(&Card::Joker) == Ordering::Equal)
@@ -125,65 +130,45 @@ impl Hand {
 }
 
 #[derive(Debug)]
-struct Position {
-    hand: Hand,
+pub struct Position {
+    cards: Vec<Card>,
     bid: u64,
 }
 
-fn parse_position(joker_instead_of_jack: bool) -> impl Fn(&str) -> IResult<&str, Position> {
-    move |input: &str| {
-        let (input, (card_chars, bid)) = separated_pair(alphanumeric1, tag(" "), u64)(input)?;
-        Ok((
-            input,
-            Position {
-                hand: Hand::new(card_chars, joker_instead_of_jack),
-                bid,
-            },
-        ))
-    }
+fn parse_position(input: &str) -> IResult<&str, Position> {
+    let (input, (card_chars, bid)) = separated_pair(alphanumeric1, tag(" "), u64)(input)?;
+    Ok((
+        input,
+        Position {
+            cards: parse_cards(card_chars),
+            bid,
+        },
+    ))
 }
 
-fn parse_input(input: &str, joker_instead_of_jack: bool) -> IResult<&str, Vec<Position>> {
-    let (input, positions) =
-        separated_list1(newline, parse_position(joker_instead_of_jack))(input)?;
-    Ok((input, positions))
+fn parse_input(input: &str) -> IResult<&str, Vec<Position>> {
+    separated_list1(newline, parse_position)(input)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (_, positions) = parse_input(input, false).unwrap();
-    let result: u64 = positions
-        .iter()
-        .sorted_by(|p1, p2| p1.hand.cmp(&p2.hand))
-        .enumerate()
-        .map(|(i, p)| (i + 1) as u64 * p.bid)
-        .sum();
-    Some(result)
+fn parse(input: &str) -> Vec<Position> {
+    let (_, positions) = parse_input(input).unwrap();
+    positions
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (_, positions) = parse_input(input, true).unwrap();
-    let result: u64 = positions
+fn total_winnings(positions: &[Position], joker_instead_of_jack: bool) -> u64 {
+    positions
         .iter()
-        .sorted_by(|p1, p2| p1.hand.cmp(&p2.hand))
+        .map(|p| (Hand::new(&p.cards, joker_instead_of_jack), p.bid))
+        .sorted_by(|(h1, _), (h2, _)| h1.cmp(h2))
         .enumerate()
-        .map(|(i, p)| (i + 1) as u64 * p.bid)
-        .sum();
-    Some(result)
+        .map(|(i, (_, bid))| (i + 1) as u64 * bid)
+        .sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6440));
-    }
+pub fn part_one(positions: &[Position]) -> Option<u64> {
+    Some(total_winnings(positions, false))
+}
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5905));
-    }
+pub fn part_two(positions: &[Position]) -> Option<u64> {
+    Some(total_winnings(positions, true))
 }
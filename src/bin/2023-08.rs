@@ -1,4 +1,4 @@
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8, parse -> Map<'a>);
 
 use num::integer::lcm;
 
@@ -14,7 +14,12 @@ use nom::{
 
 type Instruction<'a> = (&'a str, (&'a str, &'a str));
 
-fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+pub struct Map<'a> {
+    moves: &'a str,
+    instructions: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction<'_>> {
     let (input, node_id) = alphanumeric1(input)?;
     let (input, _) = tag(" = ")(input)?;
     let (input, (left, right)) = delimited(
@@ -25,16 +30,26 @@ fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
     Ok((input, (node_id, (left, right))))
 }
 
-fn parse_input(input: &str) -> IResult<&str, (&str, Vec<Instruction>)> {
+fn parse_input(input: &str) -> IResult<&str, (&str, Vec<Instruction<'_>>)> {
     let (input, moves) = alpha1(input)?;
     let (input, _) = tag("\n\n")(input)?;
     let (input, instructions) = separated_list1(newline, parse_instruction)(input)?;
     Ok((input, (moves, instructions)))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+fn parse(input: &str) -> Map<'_> {
     let (_, (moves, instructions)) = parse_input(input).unwrap();
-    let instructions_map: HashMap<&str, (&str, &str)> = instructions.into_iter().collect();
+    Map {
+        moves,
+        instructions: instructions.into_iter().collect(),
+    }
+}
+
+pub fn part_one(map: &Map) -> Option<u32> {
+    let Map {
+        moves,
+        instructions: instructions_map,
+    } = map;
     let mut move_counter = 0;
     let mut i = 0;
     let mut currend_node = "AAA";
@@ -55,9 +70,11 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(move_counter)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (_, (moves, instructions)) = parse_input(input).unwrap();
-    let instructions_map: HashMap<&str, (&str, &str)> = instructions.into_iter().collect();
+pub fn part_two(map: &Map) -> Option<u64> {
+    let Map {
+        moves,
+        instructions: instructions_map,
+    } = map;
     let starting_positions: Vec<&str> = instructions_map
         .keys()
         .filter(|&&node| node.ends_with('A'))
@@ -88,20 +105,3 @@ pub fn part_two(input: &str) -> Option<u64> {
         .collect::<Vec<u64>>();
     min_z_distances.into_iter().reduce(lcm)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples_b", DAY));
-        assert_eq!(result, Some(6));
-    }
-}
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 fn get_next_sequence(numbers: &[i32]) -> Vec<i32> {
     numbers
@@ -56,18 +56,6 @@ pub fn part_two(input: &str) -> Option<i32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(114));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-    }
-
     #[test]
     fn test_get_next_sequence() {
         let result = get_next_sequence(&[10, 13, 16, 21, 30, 45, 68]);
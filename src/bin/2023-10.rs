@@ -1,7 +1,7 @@
 use itertools::{enumerate, Itertools};
 use petgraph::{adj::NodeIndex, algo::dijkstra, graph::UnGraph};
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2023, 10);
 
 #[derive(Debug)]
 struct Board {
@@ -98,15 +98,9 @@ pub fn part_two(_: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
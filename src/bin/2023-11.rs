@@ -2,7 +2,7 @@ use std::cmp::{max, min};
 
 use itertools::{all, enumerate, Itertools};
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11);
 
 type Position = (usize, usize);
 
@@ -57,20 +57,3 @@ pub fn part_one(input: &str) -> Option<usize> {
 pub fn part_two(input: &str) -> Option<usize> {
     Some(solve(input, 1_000_000))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(374));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(82000210));
-    }
-}
@@ -5,7 +5,7 @@ use std::{
 
 use itertools::{all, Itertools};
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 fn parse(input: &str) -> Vec<(Vec<char>, Vec<u32>)> {
     input
@@ -142,18 +142,6 @@ pub fn part_two(input: &str) -> Option<usize> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(525152));
-    }
-
     #[test]
     fn test_solve_simple() {
         let mut parts: VecDeque<char> = "???".chars().collect();
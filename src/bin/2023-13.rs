@@ -5,9 +5,9 @@ use std::{
 
 use itertools::{all, Itertools};
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
-fn find_horizontal_mirror(lines: &Vec<&str>) -> Option<usize> {
+fn find_horizontal_mirror(lines: &[&str]) -> Option<usize> {
     (1..lines.len())
         .flat_map(|i| {
             let start = max(0, 2 * i as i32 - lines.len() as i32) as usize;
@@ -56,7 +56,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(result)
 }
 
-fn find_first_horizontal_smudge(lines: &Vec<&str>) -> Option<usize> {
+fn find_first_horizontal_smudge(lines: &[&str]) -> Option<usize> {
     (0..lines.len())
         .flat_map(|i| {
             let start = max(0, 2 * i as i32 - lines.len() as i32) as usize;
@@ -96,20 +96,3 @@ pub fn part_two(input: &str) -> Option<usize> {
         .sum();
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(405));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(400));
-    }
-}
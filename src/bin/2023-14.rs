@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2023, 14);
 
 fn to_columns(input: &str) -> Vec<Vec<char>> {
     let chars_with_indices: Vec<Vec<(usize, char)>> = input
@@ -19,7 +19,7 @@ fn to_columns(input: &str) -> Vec<Vec<char>> {
         .collect_vec()
 }
 
-fn calculate_column_value(col: &Vec<char>) -> u32 {
+fn calculate_column_value(col: &[char]) -> u32 {
     let mut hases_positions: VecDeque<isize> = col
         .iter()
         .enumerate()
@@ -47,7 +47,10 @@ fn calculate_column_value(col: &Vec<char>) -> u32 {
 
 pub fn part_one(input: &str) -> Option<u32> {
     let columns = to_columns(input);
-    let result = columns.iter().map(calculate_column_value).sum();
+    let result = columns
+        .iter()
+        .map(|column| calculate_column_value(column))
+        .sum();
 
     Some(result)
 }
@@ -60,15 +63,9 @@ pub fn part_two(_: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(136));
-    }
-
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 
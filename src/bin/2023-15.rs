@@ -4,7 +4,7 @@ use nom::character::complete::{alpha1, char, u32};
 use nom::multi::{many0, separated_list1};
 use nom::IResult;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2023, 15);
 
 fn hash(instruction: &str) -> u32 {
     instruction
@@ -101,18 +101,6 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1320));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(145));
-    }
-
     #[test]
     fn test_hash() {
         let result = hash("HASH");
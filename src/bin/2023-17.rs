@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use petgraph::{algo::dijkstra, graph::DiGraph};
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2023, 17);
 
 #[derive(Debug)]
 struct Board {
@@ -144,20 +144,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     let path = dijkstra(&board.graph, board.start.into(), None, |e| *e.weight());
     Some(path[&petgraph::graph::NodeIndex::from(board.target)])
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(102));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
-}
@@ -14,10 +14,10 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19, parse -> (Vec<Workflow>, Vec<Part>));
 
 #[derive(Debug)]
-struct Part {
+pub struct Part {
     x: u32,
     m: u32,
     a: u32,
@@ -83,7 +83,7 @@ impl Rule {
 }
 
 #[derive(Debug)]
-struct Workflow {
+pub struct Workflow {
     name: String,
     rules: Vec<Rule>,
 }
@@ -160,8 +160,12 @@ fn parse_input(input: &str) -> IResult<&str, (Vec<Workflow>, Vec<Part>)> {
     Ok((input, (workflows, parts)))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+fn parse(input: &str) -> (Vec<Workflow>, Vec<Part>) {
     let (_, (workflows, parts)) = parse_input(input).unwrap();
+    (workflows, parts)
+}
+
+pub fn part_one((workflows, parts): &(Vec<Workflow>, Vec<Part>)) -> Option<u32> {
     let workflow_map: HashMap<String, &Workflow> =
         workflows.iter().map(|w| (w.name.clone(), w)).collect();
     let start = State::WorkflowName {
@@ -188,7 +192,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(result)
 }
 
-pub fn part_two(_: &str) -> Option<usize> {
+pub fn part_two(_: &(Vec<Workflow>, Vec<Part>)) -> Option<usize> {
     None
 }
 
@@ -196,15 +200,10 @@ pub fn part_two(_: &str) -> Option<usize> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(19114));
-    }
-
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let result = part_two(&parse(&input));
         assert_eq!(result, None);
     }
 }
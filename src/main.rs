@@ -1,66 +1,192 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench_compare, download, extract_examples, read, scaffold, solve, stars, verify, watch,
+};
+use advent_of_code::template::input::InputSource;
+use advent_of_code::template::runner::RunOptions;
 use args::{parse, AppArguments};
 
+/// All solutions in `src/bin`, included as modules so they can run in-process. Empty without the `in_process` feature.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use std::process;
+    use std::ffi::OsString;
+    use std::time::Duration;
+    use std::{env, process};
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{input::InputSource, readme_benchmarks::ExportFormat, OutputFormat},
+        Day, Year,
+    };
 
     pub enum AppArguments {
         Download {
+            year: Year,
+            day: Day,
+            force: bool,
+            wait: bool,
+        },
+        ExtractExamples {
+            year: Year,
             day: Day,
+            yes: bool,
         },
         Read {
+            year: Year,
             day: Day,
+            wait: bool,
+            offline: bool,
         },
         Scaffold {
+            year: Year,
             day: Day,
+            template: Option<String>,
+            answer_type: Option<String>,
+            part_two_example: bool,
+            force: bool,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             time: bool,
+            mem: bool,
+            timeout: Option<Duration>,
             submit: Option<u8>,
+            format: OutputFormat,
+            input: InputSource,
+            examples: bool,
+            watch: bool,
         },
         All {
+            year: Year,
             release: bool,
             time: bool,
+            mem: bool,
+            timeout: Option<Duration>,
+            jobs: usize,
+            format: OutputFormat,
+            label: Option<String>,
+            exports: Vec<ExportFormat>,
+        },
+        BenchCompare {
+            year: Year,
+            baseline: Option<String>,
+            threshold: f64,
+        },
+        Stars {
+            year: Year,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
         },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        let mut raw_args: Vec<OsString> = env::args_os().skip(1).collect();
+        let input = InputSource::take_from_args(&mut raw_args)?;
+        let mut args = pico_args::Arguments::from_vec(raw_args);
+        let Some(subcommand) = args.subcommand()? else {
+            eprintln!("No command specified.");
+            process::exit(1);
+        };
+        // every command works on one year, `.cargo/config.toml` sets the default.
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env()?,
+        };
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
+        let app_args = match subcommand.as_str() {
+            "all" => AppArguments::All {
+                year,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                mem: args.contains("--mem"),
+                timeout: args
+                    .opt_value_from_str("--timeout")?
+                    .map(Duration::from_secs),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                label: args.opt_value_from_str("--label")?,
+                exports: args
+                    .opt_value_from_fn("--export", |formats: &str| {
+                        formats.split(',').map(str::parse).collect()
+                    })?
+                    .unwrap_or_default(),
+            },
+            "bench-compare" => AppArguments::BenchCompare {
+                year,
+                baseline: args.opt_value_from_str("--baseline")?,
+                threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+            },
+            "download" => AppArguments::Download {
+                year,
+                day: args.free_from_str()?,
+                force: args.contains("--force"),
+                wait: args.contains("--wait"),
             },
-            Some("download") => AppArguments::Download {
+            "extract-examples" => AppArguments::ExtractExamples {
+                year,
                 day: args.free_from_str()?,
+                yes: args.contains("--yes"),
             },
-            Some("read") => AppArguments::Read {
+            "read" => AppArguments::Read {
+                year,
                 day: args.free_from_str()?,
+                wait: args.contains("--wait"),
+                offline: args.contains("--offline"),
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            "scaffold" => AppArguments::Scaffold {
+                year,
                 day: args.free_from_str()?,
+                template: args.opt_value_from_str("--template")?,
+                answer_type: args.opt_value_from_str("--answer-type")?,
+                part_two_example: args.contains("--part-two-example"),
+                force: args.contains("--force"),
             },
-            Some("solve") => AppArguments::Solve {
+            "solve" => AppArguments::Solve {
+                year,
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
+                mem: args.contains("--mem"),
+                timeout: args
+                    .opt_value_from_str("--timeout")?
+                    .map(Duration::from_secs),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                input: input.clone(),
+                examples: args.contains("--examples"),
+                watch: args.contains("--watch"),
+            },
+            "stars" => AppArguments::Stars { year },
+            "verify" => AppArguments::Verify {
+                year,
+                day: args.opt_free_from_str()?,
             },
-            Some(x) => {
+            x => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
             }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
         };
 
+        if matches!(
+            app_args,
+            AppArguments::Solve {
+                examples: true,
+                submit: Some(_),
+                ..
+            }
+        ) {
+            return Err("--submit cannot be combined with --examples.".into());
+        }
+
+        if input != InputSource::Puzzle && !matches!(app_args, AppArguments::Solve { .. }) {
+            eprintln!("Warning: --input, --stdin and --example are only supported by solve.");
+        }
+
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
@@ -77,16 +203,103 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                time,
+                mem,
+                timeout,
+                jobs,
+                format,
+                label,
+                exports,
+            } => all::handle(
+                solutions::SOLUTIONS,
+                year,
+                release,
+                jobs,
+                &RunOptions {
+                    format,
+                    time,
+                    mem,
+                    timeout,
+                    submit: None,
+                    quiet: false,
+                    input: InputSource::Puzzle,
+                    examples: false,
+                },
+                label,
+                &exports,
+            ),
+            AppArguments::BenchCompare {
+                year,
+                baseline,
+                threshold,
+            } => bench_compare::handle(year, baseline, threshold),
+            AppArguments::Download {
+                year,
+                day,
+                force,
+                wait,
+            } => download::handle(year, day, force, wait),
+            AppArguments::ExtractExamples { year, day, yes } => {
+                extract_examples::handle(year, day, yes);
+            }
+            AppArguments::Stars { year } => stars::handle(year),
+            AppArguments::Verify { year, day } => verify::handle(solutions::SOLUTIONS, year, day),
+            AppArguments::Read {
+                year,
+                day,
+                wait,
+                offline,
+            } => read::handle(year, day, wait, offline),
+            AppArguments::Scaffold {
+                year,
+                day,
+                template,
+                answer_type,
+                part_two_example,
+                force,
+            } => scaffold::handle(
+                year,
+                day,
+                template.as_deref(),
+                answer_type
+                    .as_deref()
+                    .unwrap_or(scaffold::DEFAULT_ANSWER_TYPE),
+                part_two_example,
+                force,
+            ),
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 time,
+                mem,
+                timeout,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                format,
+                input,
+                examples,
+                watch,
+            } => {
+                let options = RunOptions {
+                    format,
+                    time,
+                    mem,
+                    timeout,
+                    submit,
+                    quiet: false,
+                    input,
+                    examples,
+                };
+
+                if watch {
+                    watch::handle(year, day, release, &options);
+                } else {
+                    solve::handle(solutions::SOLUTIONS, year, day, release, &options);
+                }
+            }
         },
     };
 }
@@ -0,0 +1,162 @@
+/// A global allocator that counts allocations, used to profile the memory usage of solutions.
+/// It is installed for all binaries when the `mem` feature is enabled.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::template::json::Value;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+pub struct CountingAllocator;
+
+#[cfg(feature = "mem")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // a reallocation is counted as a new allocation that frees the old one.
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Memory usage of a measured function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryUsage {
+    /// Total bytes allocated, including memory that was freed again.
+    pub allocated_bytes: usize,
+    pub allocations: usize,
+    /// Maximum of live bytes allocated by the function at any point in time.
+    pub peak_bytes: usize,
+}
+
+impl MemoryUsage {
+    pub fn to_json(&self) -> Value {
+        Value::Object(vec![
+            (
+                "allocated_bytes".into(),
+                Value::number(self.allocated_bytes),
+            ),
+            ("allocations".into(), Value::number(self.allocations)),
+            ("peak_bytes".into(), Value::number(self.peak_bytes)),
+        ])
+    }
+
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let number = |key| usize::try_from(value.get(key)?.as_u64()?).ok();
+
+        Some(Self {
+            allocated_bytes: number("allocated_bytes")?,
+            allocations: number("allocations")?,
+            peak_bytes: number("peak_bytes")?,
+        })
+    }
+}
+
+impl Display for MemoryUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak, {} in {} allocations",
+            format_bytes(self.peak_bytes),
+            format_bytes(self.allocated_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// Formats a byte count with binary prefixes, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Whether the counting allocator is installed, i.e. the solution was built with the `mem` feature.
+pub fn is_enabled() -> bool {
+    cfg!(feature = "mem")
+}
+
+/// Runs a function and measures the memory it allocates.
+/// Only meaningful if the counting allocator is installed and no other thread allocates concurrently.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, MemoryUsage) {
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = func();
+
+    let usage = MemoryUsage {
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (result, usage)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::format_bytes;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
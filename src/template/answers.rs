@@ -0,0 +1,125 @@
+/// Store of accepted answers in `data/YYYY/answers/NN.toml`, used by `cargo verify` to check solutions against them.
+use std::fmt::Display;
+use std::{fs, io};
+
+use crate::template::{get_data_dir, toml};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers: {e}"),
+            Error::IO(e) => write!(f, "could not access answers: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self, Error> {
+        let mut answers = Self::default();
+
+        for (key, value) in toml::parse(content).map_err(|e| Error::Parser(e.to_string()))? {
+            match key.as_str() {
+                "part_1" => answers.part_1 = Some(value),
+                "part_2" => answers.part_2 = Some(value),
+                key => return Err(Error::Parser(format!("unknown key \"{key}\""))),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    fn to_toml(&self, year: Year, day: Day) -> String {
+        let mut s =
+            format!("# Accepted answers for day {day} of {year}, checked by `cargo verify`.\n");
+        for (key, answer) in [("part_1", &self.part_1), ("part_2", &self.part_2)] {
+            if let Some(answer) = answer {
+                s.push_str(&format!("{key} = {}\n", toml::string(answer)));
+            }
+        }
+        s
+    }
+}
+
+#[must_use]
+pub fn get_path_for_answers(year: Year, day: Day) -> String {
+    get_data_dir(year)
+        .join("answers")
+        .join(format!("{day}.toml"))
+        .display()
+        .to_string()
+}
+
+/// Reads the answers of a day. A missing file means that no answers are known yet.
+pub fn read(year: Year, day: Day) -> Result<Answers, Error> {
+    match fs::read_to_string(get_path_for_answers(year, day)) {
+        Ok(content) => Answers::parse(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Stores an accepted answer for a part, keeping the answer of the other part.
+pub fn record(year: Year, day: Day, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = read(year, day)?;
+    answers.set(part, answer.to_string());
+    fs::create_dir_all(get_data_dir(year).join("answers"))?;
+    fs::write(get_path_for_answers(year, day), answers.to_toml(year, day))?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Answers;
+    use crate::{day, year};
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = Answers {
+            part_1: Some("142".into()),
+            part_2: None,
+        };
+        let content = answers.to_toml(year!(2023), day!(1));
+        assert!(!content.contains("part_2"));
+        assert_eq!(Answers::parse(&content).unwrap(), answers);
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(Answers::parse("part_3 = \"1\"").is_err());
+    }
+}
@@ -0,0 +1,569 @@
+/// Client for the Advent of Code website: fetches inputs and puzzle pages and submits answers.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable or from an `.adventofcode.session` file in
+/// the home directory. `AOC_BASE_URL` replaces `https://adventofcode.com`, e.g. to run against a local mock server.
+/// `AOC_USER_AGENT` sets the user agent that identifies the client to the website.
+///
+/// To go easy on the website, requests are throttled across processes via the timestamp in [`THROTTLE_PATH`] and
+/// files that were already downloaded are not fetched again.
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io, thread};
+
+use crate::template::get_data_dir;
+use crate::template::markdown::{articles, decode_entities, puzzle_to_markdown};
+use crate::{Day, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE: &str = ".adventofcode.session";
+/// Identifies the client to the maintainers of the website, as they ask automated tools to do, unless
+/// `AOC_USER_AGENT` is set: the `repository` of `Cargo.toml`, or the template this project started from.
+const DEFAULT_USER_AGENT: &str = match env!("CARGO_PKG_REPOSITORY").as_bytes() {
+    [] => "github.com/fspoettel/advent-of-code-rust",
+    _ => env!("CARGO_PKG_REPOSITORY"),
+};
+
+/// Holds the time of the last request to the website, in milliseconds since the unix epoch.
+pub const THROTTLE_PATH: &str = "data/.last_request";
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Puzzles unlock at midnight EST, i.e. at 05:00 UTC.
+const RELEASE_HOUR_UTC: u64 = 5;
+
+#[derive(Debug)]
+pub enum Error {
+    MissingSession,
+    /// The puzzle unlocks after this time.
+    NotReleased(Duration),
+    Http(u16, String),
+    Transport(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<ureq::Error> for Error {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                let body = response.into_string().unwrap_or_default();
+                Error::Http(status, body.trim().to_string())
+            }
+            ureq::Error::Transport(e) => Error::Transport(e.to_string()),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found, set AOC_SESSION or create ~/{SESSION_FILE}."
+            ),
+            Error::NotReleased(wait) => write!(
+                f,
+                "the puzzle is not released yet, it unlocks in {}. Pass --wait to wait for it.",
+                format_wait(*wait)
+            ),
+            // the website answers 400 for requests without a valid session.
+            Error::Http(400, _) => {
+                write!(f, "the session cookie was rejected, it may have expired.")
+            }
+            Error::Http(404, _) => {
+                write!(f, "the puzzle was not found, it may not be released yet.")
+            }
+            Error::Http(status, body) => write!(f, "the server responded with {status}: {body}"),
+            Error::Transport(e) => write!(f, "could not reach the server: {e}"),
+            Error::IO(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. Contains the time left to wait, if the response mentions it.
+    RateLimited(Option<Duration>),
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    /// A response that is not recognized, as text.
+    Unknown(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Verdict::RateLimited(Some(wait)) => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Verdict::RateLimited(None) => write!(f, "You gave an answer too recently."),
+            Verdict::WrongLevel => {
+                write!(f, "This part is already solved or not unlocked yet.")
+            }
+            Verdict::Unknown(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    /// The timestamp file that requests are throttled with, if any.
+    throttle: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(user_agent)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            throttle: None,
+        }
+    }
+
+    /// Creates a client with the session cookie, base url and user agent of the environment, throttled via
+    /// [`THROTTLE_PATH`].
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let user_agent = env::var("AOC_USER_AGENT").unwrap_or_else(|_| DEFAULT_USER_AGENT.into());
+        Ok(Self {
+            throttle: Some(PathBuf::from(THROTTLE_PATH)),
+            ..Self::new(&base_url, &read_session()?, &user_agent)
+        })
+    }
+
+    pub fn get_input(&self, year: Year, day: Day) -> Result<String, Error> {
+        self.get(&format!("/{year}/day/{}/input", day.into_inner()))
+    }
+
+    /// The HTML page of a puzzle. It contains the description of part two once part one is solved.
+    pub fn get_puzzle(&self, year: Year, day: Day) -> Result<String, Error> {
+        self.get(&format!("/{year}/day/{}", day.into_inner()))
+    }
+
+    pub fn submit(&self, year: Year, day: Day, part: u8, answer: &str) -> Result<Verdict, Error> {
+        let url = format!("{}/{year}/day/{}/answer", self.base_url, day.into_inner());
+        self.throttle();
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+        Ok(parse_verdict(&html))
+    }
+
+    fn get(&self, path: &str) -> Result<String, Error> {
+        self.throttle();
+        let body = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()?;
+        Ok(body)
+    }
+
+    /// Waits until [`MIN_REQUEST_INTERVAL`] passed since the last request of any process and records this one.
+    /// The timestamp file is best-effort: if it cannot be read or written, requests are not throttled.
+    fn throttle(&self) {
+        let Some(path) = &self.throttle else {
+            return;
+        };
+
+        let last = fs::read_to_string(path)
+            .ok()
+            .and_then(|last| last.trim().parse().ok());
+        let wait = throttle_wait(last, now_millis());
+
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(path, now_millis().to_string());
+    }
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis())
+}
+
+/// How long to wait before the next request, given the time of the last one in milliseconds since the unix epoch.
+fn throttle_wait(last: Option<u128>, now: u128) -> Duration {
+    let Some(last) = last else {
+        return Duration::ZERO;
+    };
+
+    let elapsed = now.saturating_sub(last);
+    let interval = MIN_REQUEST_INTERVAL.as_millis();
+    // a timestamp from the future (e.g. after changing the clock) does not block requests forever.
+    if last > now || elapsed >= interval {
+        Duration::ZERO
+    } else {
+        Duration::from_millis(u64::try_from(interval - elapsed).unwrap_or(0))
+    }
+}
+
+/// The time a puzzle unlocks, in seconds since the unix epoch.
+pub fn release_timestamp(year: Year, day: Day) -> u64 {
+    // see: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    // puzzles are released in december, which is month 9 if the year starts in march.
+    let year = u64::from(year.into_inner());
+    let era = year / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * 9 + 2) / 5 + u64::from(day.into_inner()) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    days * 86400 + RELEASE_HOUR_UTC * 3600
+}
+
+/// Checks that a puzzle is released. With `wait`, sleeps until it is instead of returning an error.
+pub fn ensure_released(year: Year, day: Day, wait: bool) -> Result<(), Error> {
+    let release = release_timestamp(year, day);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    if now >= release {
+        return Ok(());
+    }
+
+    let remaining = Duration::from_secs(release - now);
+    if !wait {
+        return Err(Error::NotReleased(remaining));
+    }
+
+    println!(
+        "⏳ Waiting {} for day {day} to unlock, press Ctrl-C to stop.",
+        format_wait(remaining)
+    );
+    // the clock of the website may be a bit behind.
+    thread::sleep(remaining + Duration::from_secs(2));
+    Ok(())
+}
+
+/// Formats a duration as e.g. `2h 5m 3s`, or `3d 2h` for long waits.
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    match (
+        secs / 86400,
+        secs % 86400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    ) {
+        (0, 0, 0, s) => format!("{s}s"),
+        (0, 0, m, s) => format!("{m}m {s}s"),
+        (0, h, m, s) => format!("{h}h {m}m {s}s"),
+        (d, h, _, _) => format!("{d}d {h}h"),
+    }
+}
+
+fn read_session() -> Result<String, Error> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session);
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or(Error::MissingSession)?;
+    let session = fs::read_to_string(PathBuf::from(home).join(SESSION_FILE))
+        .map_err(|_| Error::MissingSession)?;
+
+    if session.trim().is_empty() {
+        Err(Error::MissingSession)
+    } else {
+        Ok(session)
+    }
+}
+
+/// Fetches the puzzle description and prints it.
+pub fn read(year: Year, day: Day, wait: bool) -> Result<(), Error> {
+    ensure_released(year, day, wait)?;
+    let client = Client::from_env()?;
+    let puzzle = puzzle_to_markdown(&client.get_puzzle(year, day)?);
+
+    fs::create_dir_all(get_data_dir(year).join("puzzles"))?;
+    fs::write(get_path_for_puzzle(year, day), &puzzle)?;
+
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Downloads the input and puzzle description of a day. Files that exist and are not empty are only downloaded
+/// again with `force`.
+pub fn download(year: Year, day: Day, force: bool, wait: bool) -> Result<(), Error> {
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_path_for_puzzle(year, day);
+
+    let is_missing = |path: &str| force || fs::metadata(path).map_or(true, |m| m.len() == 0);
+    let (needs_input, needs_puzzle) = (is_missing(&input_path), is_missing(&puzzle_path));
+
+    for (needed, path) in [(needs_input, &input_path), (needs_puzzle, &puzzle_path)] {
+        if !needed {
+            println!("Skipped \"{path}\", it already exists. Pass --force to download it again.");
+        }
+    }
+
+    if !needs_input && !needs_puzzle {
+        return Ok(());
+    }
+
+    ensure_released(year, day, wait)?;
+    let client = Client::from_env()?;
+
+    // the folders of a year do not exist until its first download.
+    for folder in ["inputs", "puzzles"] {
+        fs::create_dir_all(get_data_dir(year).join(folder))?;
+    }
+
+    if needs_input {
+        write_file(&input_path, &client.get_input(year, day)?)?;
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+
+    if needs_puzzle {
+        write_file(
+            &puzzle_path,
+            &puzzle_to_markdown(&client.get_puzzle(year, day)?),
+        )?;
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    }
+
+    Ok(())
+}
+
+/// Writes a file via a temporary file, so that an interrupted download does not leave a partial file behind that
+/// would be skipped by the next download.
+fn write_file(path: &str, content: &str) -> io::Result<()> {
+    let tmp = Path::new(path).with_extension("part");
+    fs::write(&tmp, content)?;
+    fs::rename(&tmp, path)
+}
+
+pub fn submit(year: Year, day: Day, part: u8, answer: &str) -> Result<Verdict, Error> {
+    Client::from_env()?.submit(year, day, part, answer)
+}
+
+fn get_input_path(year: Year, day: Day) -> String {
+    get_data_dir(year)
+        .join("inputs")
+        .join(format!("{day}.txt"))
+        .display()
+        .to_string()
+}
+
+#[must_use]
+pub fn get_path_for_puzzle(year: Year, day: Day) -> String {
+    get_data_dir(year)
+        .join("puzzles")
+        .join(format!("{day}.md"))
+        .display()
+        .to_string()
+}
+
+/// Removes the tags of an HTML fragment.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    decode_entities(&text)
+}
+
+fn parse_verdict(html: &str) -> Verdict {
+    let text = articles(html)
+        .first()
+        .map_or_else(|| strip_tags(html), |article| strip_tags(article));
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// Parses the wait time of a rate-limited response, e.g. "You have 4m 20s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (head, _) = text.split_once(" left to wait")?;
+    let (_, wait) = head.rsplit_once("have ")?;
+
+    wait.split_whitespace()
+        .map(|unit| {
+            let (value, suffix) = unit.split_at(unit.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match suffix {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{parse_verdict, release_timestamp, throttle_wait, Client, Verdict};
+    use crate::{day, year};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    fn response(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            parse_verdict(&response(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(&response(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_verdict(&response(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict(&response(
+                "That's not the right answer. If you're stuck, ..."
+            )),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            parse_verdict(&response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 20s left to wait.")),
+            Verdict::RateLimited(Some(Duration::from_secs(260)))
+        );
+        assert_eq!(
+            parse_verdict(&response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn computes_release_times() {
+        // 2023-12-01 05:00 UTC
+        assert_eq!(release_timestamp(year!(2023), day!(1)), 1_701_406_800);
+        // 2015-12-25 05:00 UTC
+        assert_eq!(release_timestamp(year!(2015), day!(25)), 1_451_019_600);
+    }
+
+    #[test]
+    fn throttles_requests() {
+        assert_eq!(throttle_wait(None, 10_000), Duration::ZERO);
+        assert_eq!(throttle_wait(Some(9_000), 10_000), Duration::from_secs(2));
+        assert_eq!(throttle_wait(Some(7_000), 10_000), Duration::ZERO);
+        assert_eq!(throttle_wait(Some(20_000), 10_000), Duration::ZERO);
+    }
+
+    #[test]
+    fn submits_to_base_url() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut head = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let page = response("That's the right answer!");
+            let reply = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                page.len()
+            );
+            reader.get_mut().write_all(reply.as_bytes()).unwrap();
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        let client = Client::new(&base_url, "abc\n", "example.com/aoc (me@example.com)");
+        let verdict = client.submit(year!(2023), day!(1), 2, "281").unwrap();
+        let (head, body) = server.join().unwrap();
+
+        assert_eq!(verdict, Verdict::Correct);
+        assert!(head.starts_with("POST /2023/day/1/answer HTTP/1.1"));
+        assert!(head.contains("Cookie: session=abc\r\n"));
+        assert!(head
+            .to_lowercase()
+            .contains("user-agent: example.com/aoc (me@example.com)\r\n"));
+        assert_eq!(body, "level=2&answer=281");
+    }
+}
@@ -0,0 +1,409 @@
+/// Module that keeps a history of benchmark runs in a JSON lines file and compares runs against each other.
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::alloc::MemoryUsage;
+use crate::template::get_data_dir;
+use crate::template::json::{self, Value};
+use crate::template::readme_benchmarks::{PartTiming, Timings};
+use crate::template::record::Status;
+use crate::template::stats::Stats;
+use crate::{Day, Year};
+
+/// The history of a year, e.g. `data/2023/benchmarks.jsonl`.
+#[must_use]
+pub fn get_path_for_history(year: Year) -> String {
+    get_data_dir(year)
+        .join("benchmarks.jsonl")
+        .display()
+        .to_string()
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<json::Error> for Error {
+    fn from(e: json::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// A single benchmark run, i.e. one invocation of `cargo time`.
+#[derive(Clone)]
+pub struct Run {
+    /// Output of `git describe --always --dirty`, if available.
+    pub commit: Option<String>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Optional name that allows using this run as a baseline.
+    pub label: Option<String>,
+    pub timings: Vec<Timings>,
+}
+
+impl Run {
+    pub fn new(timings: Vec<Timings>, label: Option<String>) -> Self {
+        Self {
+            commit: git_commit(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            label,
+            timings,
+        }
+    }
+
+    /// Checks whether this run is referenced by a baseline name, either via its label or a prefix of its commit.
+    pub fn matches(&self, baseline: &str) -> bool {
+        self.label.as_deref() == Some(baseline)
+            || self
+                .commit
+                .as_deref()
+                .is_some_and(|commit| commit.starts_with(baseline))
+    }
+
+    pub fn describe(&self) -> String {
+        let mut s = format!(
+            "{} ({})",
+            self.commit.as_deref().unwrap_or("unknown commit"),
+            format_timestamp(self.timestamp)
+        );
+        if let Some(label) = &self.label {
+            s.push_str(&format!(" \"{label}\""));
+        }
+        s
+    }
+
+    fn to_json(&self) -> Value {
+        let optional_stats = |timing: Option<PartTiming>| timing.map_or(Value::Null, part_to_json);
+
+        Value::Object(vec![
+            (
+                "commit".into(),
+                self.commit.clone().map_or(Value::Null, Value::String),
+            ),
+            ("timestamp".into(), Value::number(self.timestamp)),
+            (
+                "label".into(),
+                self.label.clone().map_or(Value::Null, Value::String),
+            ),
+            (
+                "days".into(),
+                Value::Array(
+                    self.timings
+                        .iter()
+                        .map(|timing| {
+                            Value::Object(vec![
+                                ("day".into(), Value::number(timing.day.into_inner())),
+                                ("parse".into(), optional_stats(timing.parse)),
+                                ("part_1".into(), optional_stats(timing.part_1)),
+                                ("part_2".into(), optional_stats(timing.part_2)),
+                                ("total_ns".into(), Value::number(timing.total_nanos)),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let optional_stats = |day: &Value, key| match day.get(key) {
+            None | Some(Value::Null) => Some(None),
+            Some(stats) => part_from_json(stats).map(Some),
+        };
+
+        let timings = value
+            .get("days")?
+            .as_array()?
+            .iter()
+            .map(|day| {
+                Some(Timings {
+                    day: Day::new(u8::try_from(day.get("day")?.as_u64()?).ok()?)?,
+                    parse: optional_stats(day, "parse")?,
+                    part_1: optional_stats(day, "part_1")?,
+                    part_2: optional_stats(day, "part_2")?,
+                    total_nanos: day.get("total_ns")?.as_f64()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            commit: value.get("commit")?.as_str().map(Into::into),
+            timestamp: value.get("timestamp")?.as_u64()?,
+            label: value.get("label")?.as_str().map(Into::into),
+            timings,
+        })
+    }
+}
+
+/// Stores the memory usage of a part, if measured, under a nested `memory` key next to its statistics.
+fn part_to_json(timing: PartTiming) -> Value {
+    let mut value = timing.stats.to_json();
+    if let Value::Object(fields) = &mut value {
+        if let Some(memory) = timing.memory {
+            fields.push(("memory".into(), memory.to_json()));
+        }
+        fields.push(("status".into(), Value::string(timing.status.as_str())));
+    }
+    value
+}
+
+fn part_from_json(value: &Value) -> Option<PartTiming> {
+    let memory = match value.get("memory") {
+        None | Some(Value::Null) => None,
+        Some(memory) => Some(MemoryUsage::from_json(memory)?),
+    };
+
+    // older runs only contain solved parts.
+    let status = match value.get("status") {
+        None => Status::Solved,
+        Some(status) => status.as_str()?.parse().ok()?,
+    };
+
+    Some(PartTiming {
+        stats: Stats::from_json(value)?,
+        memory,
+        status,
+    })
+}
+
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+/// Formats a unix timestamp as `YYYY-MM-DD HH:MM UTC`.
+fn format_timestamp(timestamp: u64) -> String {
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = timestamp / 86400;
+    let seconds = timestamp % 86400;
+
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        seconds / 3600,
+        (seconds % 3600) / 60
+    )
+}
+
+fn parse_history(content: &str) -> Result<Vec<Run>, Error> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            Run::from_json(&Value::parse(line)?)
+                .ok_or_else(|| Error::Parser(format!("malformed entry on line {}", i + 1)))
+        })
+        .collect()
+}
+
+/// Reads all recorded runs, oldest first. A missing history file is treated as an empty history.
+pub fn read(year: Year) -> Result<Vec<Run>, Error> {
+    match fs::read_to_string(get_path_for_history(year)) {
+        Ok(content) => parse_history(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn append(year: Year, run: &Run) -> Result<(), Error> {
+    fs::create_dir_all(get_data_dir(year))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path_for_history(year))?;
+    writeln!(file, "{}", run.to_json())?;
+    Ok(())
+}
+
+/// The change of one part's median between two runs.
+#[derive(Debug, PartialEq)]
+pub struct Change {
+    pub day: Day,
+    pub part: &'static str,
+    pub before: Stats,
+    pub after: Stats,
+}
+
+impl Change {
+    /// Relative change of the median in percent, positive values mean the part got slower.
+    pub fn percent(&self) -> f64 {
+        let before = self.before.median.as_secs_f64();
+        if before == 0.0 {
+            return 0.0;
+        }
+        (self.after.median.as_secs_f64() - before) / before * 100.0
+    }
+
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.percent() > threshold_percent
+    }
+}
+
+/// Collects the changes of all parts that are present in both runs.
+pub fn compare(baseline: &Run, current: &Run) -> Vec<Change> {
+    let mut changes = vec![];
+
+    for after in &current.timings {
+        let Some(before) = baseline.timings.iter().find(|t| t.day == after.day) else {
+            continue;
+        };
+
+        let parts = [
+            ("Parse", before.parse, after.parse),
+            ("Part 1", before.part_1, after.part_1),
+            ("Part 2", before.part_2, after.part_2),
+        ];
+
+        for (part, before_stats, after_stats) in parts {
+            if let (Some(before_stats), Some(after_stats)) = (before_stats, after_stats) {
+                if before_stats.status != Status::Solved || after_stats.status != Status::Solved {
+                    continue;
+                }
+
+                changes.push(Change {
+                    day: after.day,
+                    part,
+                    before: before_stats.stats,
+                    after: after_stats.stats,
+                });
+            }
+        }
+    }
+
+    changes
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{compare, format_timestamp, parse_history, Run};
+    use crate::day;
+    use crate::template::alloc::MemoryUsage;
+    use crate::template::readme_benchmarks::{PartTiming, Timings};
+    use crate::template::record::Status;
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+
+    fn run(commit: &str, label: Option<&str>, part_1_micros: u64) -> Run {
+        Run {
+            commit: Some(commit.into()),
+            timestamp: 1_702_962_000,
+            label: label.map(Into::into),
+            timings: vec![Timings {
+                day: day!(1),
+                parse: None,
+                part_1: Some(PartTiming {
+                    stats: Stats::single(Duration::from_micros(part_1_micros)),
+                    memory: Some(MemoryUsage {
+                        allocated_bytes: 2048,
+                        allocations: 3,
+                        peak_bytes: 1024,
+                    }),
+                    status: Status::Solved,
+                }),
+                part_2: None,
+                total_nanos: 1000.0 * part_1_micros as f64,
+            }],
+        }
+    }
+
+    #[test]
+    fn roundtrips_history() {
+        let runs = [
+            run("abc1234", None, 10),
+            run("def5678-dirty", Some("fast"), 20),
+        ];
+        let content = runs
+            .iter()
+            .map(|r| r.to_json().to_string() + "\n")
+            .collect::<String>();
+        let parsed = parse_history(&content).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].label.as_deref(), Some("fast"));
+        assert_eq!(parsed[1].timings[0].part_1, runs[1].timings[0].part_1);
+        assert_eq!(parsed[1].timings[0].part_2, None);
+    }
+
+    #[test]
+    fn rejects_malformed_history() {
+        assert!(parse_history("{\"commit\": null}\n").is_err());
+    }
+
+    #[test]
+    fn matches_baselines() {
+        let run = run("abc1234", Some("fast"), 10);
+        assert!(run.matches("fast"));
+        assert!(run.matches("abc"));
+        assert!(!run.matches("def"));
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let changes = compare(&run("a", None, 10), &run("b", None, 12));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].part, "Part 1");
+        assert!(changes[0].is_regression(10.0));
+        assert!(!changes[0].is_regression(25.0));
+    }
+
+    #[test]
+    fn skips_unfinished_parts() {
+        let mut current = run("b", None, 5000);
+        if let Some(part_1) = current.timings[0].part_1.as_mut() {
+            part_1.status = Status::TimedOut;
+        }
+        let content = current.to_json().to_string();
+        let parsed = parse_history(&content).unwrap();
+        assert_eq!(
+            parsed[0].timings[0].part_1.unwrap().status,
+            Status::TimedOut
+        );
+        assert!(compare(&run("a", None, 10), &parsed[0]).is_empty());
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_timestamp(1_701_406_800), "2023-12-01 05:00 UTC");
+    }
+}
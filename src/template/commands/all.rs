@@ -1,45 +1,212 @@
+use std::fmt::Display;
 use std::io;
 
 use crate::template::{
-    readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    benchmark_history::{self, Run},
+    readme_benchmarks::{self, ExportFormat, Timings},
+    record::{Record, Status},
+    registry::{self, Entry},
+    runner::{
+        format_duration, format_memory, part_name, print_failure, print_parse, print_result,
+        print_timeout, RunOptions,
+    },
+    stats::Stats,
+    try_read_file, OutputFormat, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
-
-pub fn handle(is_release: bool, is_timed: bool) {
+use crate::{all_days, Day, Year};
+
+/// Runs all solutions. They run in-process if possible (see [`registry::can_run_in_process`]), otherwise (e.g. with
+/// `--timeout`, which needs to be able to kill a solution) every solution is built and run as its own binary.
+///
+/// With more than one job, days run concurrently in their own binaries. Their output is still printed in order.
+///
+/// Benchmarks are written to the `exports` in addition to the README.
+pub fn handle(
+    solutions: &[Entry],
+    year: Year,
+    is_release: bool,
+    jobs: usize,
+    options: &RunOptions,
+    label: Option<String>,
+    exports: &[ExportFormat],
+) {
     let mut timings: Vec<Timings> = vec![];
+    let is_text = options.format == OutputFormat::Text;
+    let is_timed = options.time;
+
+    let jobs = if is_timed && jobs > 1 {
+        eprintln!("Benchmarks run serially to keep timings clean, ignoring `--jobs {jobs}`.");
+        1
+    } else {
+        jobs.max(1)
+    };
+
+    let in_process = jobs == 1
+        && options.timeout.is_none()
+        && registry::can_run_in_process(is_release, options.mem);
+
+    let mut report = |day: Day, records: Vec<Record>| {
+        if records.is_empty() {
+            if is_text {
+                println!("Not solved.");
+            }
+        } else {
+            timings.push(child_commands::timings_from_records(&records, day));
+        }
+    };
+
+    if jobs > 1 {
+        child_commands::run_parallel(year, jobs, is_release, options, |day, output| {
+            print_header(day, is_text);
+            report(day, print_output(day, &output, options.format));
+        });
+    } else {
+        all_days().for_each(|day| {
+            print_header(day, is_text);
+
+            let records = if in_process {
+                run_in_process(solutions, year, day, options)
+            } else {
+                let output = child_commands::run_solution(year, day, is_release, options, false)
+                    .unwrap_or_else(|e| child_commands::failed_output(day, &e));
+                print_output(day, &output, options.format)
+            };
+
+            report(day, records);
+        });
+    }
+
+    if is_timed {
+        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
-    all_days().for_each(|day| {
+        // the last recorded run, to show what changed since then.
+        let previous = match benchmark_history::read(year) {
+            Ok(mut runs) => runs.pop().map(|run| run.timings),
+            Err(e) => {
+                eprintln!("Failed to read benchmark history: {e}");
+                None
+            }
+        };
+
+        for format in exports {
+            match readme_benchmarks::export(
+                year,
+                *format,
+                &timings,
+                previous.as_deref(),
+                total_millis,
+            ) {
+                Ok(path) => eprintln!("Successfully exported benchmarks to \"{path}\"."),
+                Err(e) => eprintln!("Failed to export benchmarks: {e}"),
+            }
+        }
+
+        if is_release {
+            match benchmark_history::append(year, &Run::new(timings.clone(), label)) {
+                Ok(()) => eprintln!("Successfully recorded benchmarks in history."),
+                Err(e) => eprintln!("Failed to record benchmarks in history: {e}"),
+            }
+
+            match readme_benchmarks::update(year, &timings, previous.as_deref(), total_millis) {
+                Ok(()) => eprintln!("Successfully updated README with benchmarks."),
+                Err(e) => {
+                    eprintln!("Failed to update readme with benchmarks: {e}");
+                }
+            }
+        }
+    }
+}
+
+/// Runs the solution bin of a day and returns its records without printing them.
+pub fn run_bin(year: Year, day: Day, is_release: bool, options: &RunOptions) -> Vec<Record> {
+    let output = child_commands::run_solution(year, day, is_release, options, false)
+        .unwrap_or_else(|e| child_commands::failed_output(day, &e));
+    child_commands::parse_records(&output.stdout, day).0
+}
+
+/// Runs a registered solution. The runner prints its records itself.
+fn run_in_process(solutions: &[Entry], year: Year, day: Day, options: &RunOptions) -> Vec<Record> {
+    let Some(solution) = registry::find(solutions, year, day) else {
+        return vec![];
+    };
+
+    match try_read_file("inputs", year, day) {
+        Ok(input) => (solution.run)(&input, options),
+        Err(e) => {
+            let record = Record {
+                day,
+                part: 1,
+                answer: None,
+                stats: Stats::default(),
+                memory: None,
+                error: Some(format!("could not open input file: {e}")),
+                status: Status::Failed,
+            };
+            print_record(&record, options.format);
+            vec![record]
+        }
+    }
+}
+
+fn print_header(day: Day, is_text: bool) {
+    if is_text {
         if day > 1 {
             println!();
         }
 
         println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
         println!("------");
+    }
+}
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+/// Prints the output of a solution binary and returns the records it reported.
+fn print_output(day: Day, output: &child_commands::Output, format: OutputFormat) -> Vec<Record> {
+    for line in &output.stderr {
+        eprintln!("{line}");
+    }
+
+    let (records, other_lines) = child_commands::parse_records(&output.stdout, day);
 
-        if output.is_empty() {
-            println!("Not solved.");
+    for line in other_lines {
+        // keep the stdout of `--format json` parseable by moving any other output to stderr.
+        if format == OutputFormat::Text {
+            println!("{line}");
         } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+            eprintln!("{line}");
         }
-    });
+    }
 
-    if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+    for record in &records {
+        print_record(record, format);
+    }
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+    records
+}
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
-                }
-            }
+fn print_record(record: &Record, format: OutputFormat) {
+    match format {
+        OutputFormat::Text if record.status == Status::TimedOut => {
+            print_timeout(&part_name(record.part), record.stats.median);
+        }
+        OutputFormat::Text if record.status.is_failure() => {
+            let error = record.error.as_deref().unwrap_or("failed");
+            print_failure(&part_name(record.part), error);
         }
+        OutputFormat::Text if record.part == 0 => {
+            print_parse(&(format_duration(&record.stats) + &format_memory(record.memory)));
+        }
+        OutputFormat::Text => print_result(
+            &record.answer,
+            &part_name(record.part),
+            &(format_duration(&record.stats) + &format_memory(record.memory)),
+        ),
+        OutputFormat::Json => println!("{record}"),
     }
 }
 
@@ -56,47 +223,155 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read the output of the solution"),
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading the records they output.
 mod child_commands {
-    use super::{get_path_for_bin, Error};
+    use super::Error;
+    use crate::template::get_bin_name;
+    use crate::template::readme_benchmarks::{get_path_for_bin, PartTiming};
+    use crate::template::record::{Record, Status};
+    use crate::template::runner::RunOptions;
+    use crate::template::stats::Stats;
     use crate::Day;
+    use crate::{all_days, Year};
     use std::{
+        env,
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
+        sync::atomic::{AtomicUsize, Ordering},
+        sync::mpsc::{self, RecvTimeoutError},
         thread,
+        time::Duration,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// How long to wait for a killed child's next line on top of the `--timeout`, e.g. for process startup and input reads.
+    const TIMEOUT_GRACE: Duration = Duration::from_secs(5);
+
+    /// Lines a solution bin wrote to stdout and, if buffered, to stderr.
+    #[derive(Default)]
+    pub struct Output {
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+    }
+
+    /// Runs the solution bins of all days on `jobs` threads.
+    /// `report` is called on the current thread with the output of each day, in order of the days.
+    pub fn run_parallel(
+        year: Year,
+        jobs: usize,
+        is_release: bool,
+        options: &RunOptions,
+        mut report: impl FnMut(Day, Output),
+    ) {
+        let days: Vec<Day> = all_days().collect();
+        let next_day = AtomicUsize::new(0);
+        let (tx, rx) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs {
+                let (tx, days, next_day) = (tx.clone(), &days, &next_day);
+
+                scope.spawn(move || loop {
+                    let index = next_day.fetch_add(1, Ordering::Relaxed);
+                    let Some(&day) = days.get(index) else {
+                        break;
+                    };
+
+                    let output = run_solution(year, day, is_release, options, true)
+                        .unwrap_or_else(|e| failed_output(day, &e));
+                    if tx.send((index, output)).is_err() {
+                        break;
+                    }
+                });
+            }
+
+            drop(tx);
+
+            // days finish out of order, hold back their output until all previous days were reported.
+            let mut finished: Vec<Option<Output>> = days.iter().map(|_| None).collect();
+            let mut next_report = 0;
+
+            for (index, output) in rx {
+                finished[index] = Some(output);
+
+                while let Some(output) = finished.get_mut(next_report).and_then(Option::take) {
+                    report(days[next_report], output);
+                    next_report += 1;
+                }
+            }
+        });
+    }
+
+    /// Run the solution bin for a given day and collect its stdout lines.
+    /// Its stderr is forwarded as it is written, unless `buffer_stderr` is set.
+    ///
+    /// The bin is built with `cargo build` first and then invoked directly, so that compile time does not count towards
+    /// the `timeout` and killing the child does not leave an orphaned solution process behind.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_release: bool,
+        options: &RunOptions,
+        buffer_stderr: bool,
+    ) -> Result<Output, Error> {
+        let timeout = options.timeout;
+
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            return Ok(Output::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = get_bin_name(year, day);
+        let mut build_args = vec!["build", "--quiet", "--bin", &bin_name];
 
         if is_release {
-            args.push("--release");
+            build_args.push("--release");
         }
 
-        if is_timed {
+        if options.mem {
+            // the counting allocator is only compiled in with the `mem` feature.
+            build_args.extend(["--features", "mem"]);
+        }
+
+        if !Command::new("cargo").args(&build_args).status()?.success() {
+            let record = unfinished_record(day, 1, Status::Failed, Some("failed to build".into()));
+            return Ok(Output {
+                stdout: vec![record.to_string()],
+                stderr: vec![],
+            });
+        }
+
+        // solutions report their results as json records, see `parse_records`.
+        let mut args = vec!["--format".to_string(), "json".to_string()];
+
+        if options.time {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            args.push("--time".into());
+        }
+
+        if options.mem {
+            args.push("--mem".into());
+        }
+
+        if let Some(timeout) = timeout {
+            args.extend(["--timeout".into(), timeout.as_secs().to_string()]);
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(get_path_for_executable(&bin_name, is_release))
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -105,89 +380,166 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
+        let stderr_thread = thread::spawn(move || {
+            let mut buffered = vec![];
             stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                if buffer_stderr {
+                    buffered.push(line.unwrap());
+                } else {
+                    eprintln!("{}", line.unwrap());
+                }
             });
+            buffered
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let (tx, rx) = mpsc::channel();
+
+        let stdout_thread = thread::spawn(move || {
+            for line in stdout.lines() {
+                if tx.send(line.unwrap()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut output = vec![];
+
+        loop {
+            // the solution reports timeouts itself, the deadline here is a fallback in case its watchdog does not fire.
+            let line = match timeout {
+                Some(timeout) => rx.recv_timeout(timeout + TIMEOUT_GRACE),
+                None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match line {
+                Ok(line) => output.push(line),
+                Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) => {
+                    cmd.kill()?;
+                    let record = Record {
+                        stats: Stats::single(timeout.unwrap_or_default()),
+                        ..unfinished_record(day, next_part(&output, day), Status::TimedOut, None)
+                    };
+                    output.push(record.to_string());
+                    break;
+                }
+            }
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        stdout_thread.join().unwrap();
+        let stderr = stderr_thread.join().unwrap();
+        let status = cmd.wait()?;
+
+        // a solution that crashed outside of a part (e.g. while reading its input) did not report the failure itself.
+        let (records, _) = parse_records(&output, day);
+        let is_reported = records
+            .iter()
+            .any(|record| record.status.is_failure() || record.status == Status::TimedOut);
+
+        if !status.success() && !is_reported {
+            let error = format!("exited with {status}");
+            let record =
+                unfinished_record(day, next_part(&output, day), Status::Failed, Some(error));
+            output.push(record.to_string());
+        }
 
-        Ok(output)
+        Ok(Output {
+            stdout: output,
+            stderr,
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
+    /// The output of a day whose solution bin could not be built or run, e.g. because `cargo` is missing from
+    /// `PATH`. It reports the error as a failure of the day, so that the other days still run.
+    pub fn failed_output(day: Day, error: &Error) -> Output {
+        let record = unfinished_record(day, 1, Status::Failed, Some(error.to_string()));
+        Output {
+            stdout: vec![record.to_string()],
+            stderr: vec![],
+        }
+    }
+
+    /// The part after the last one the solution reported.
+    fn next_part(output: &[String], day: Day) -> u8 {
+        let (records, _) = parse_records(output, day);
+        records.last().map_or(1, |record| record.part + 1)
+    }
+
+    fn get_path_for_executable(bin_name: &str, is_release: bool) -> PathBuf {
+        let target_dir = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+        let profile = if is_release { "release" } else { "debug" };
+        Path::new(&target_dir)
+            .join(profile)
+            .join(format!("{bin_name}{}", env::consts::EXE_SUFFIX))
+    }
+
+    /// A record for a part that the solution could not report itself because it was killed or crashed.
+    fn unfinished_record(day: Day, part: u8, status: Status, error: Option<String>) -> Record {
+        Record {
+            day,
+            part,
+            answer: None,
+            stats: Stats::default(),
+            memory: None,
+            error,
+            status,
+        }
+    }
+
+    /// Splits the output of a solution bin into its records and any other lines (e.g. `dbg!` output).
+    pub fn parse_records(output: &[String], day: Day) -> (Vec<Record>, Vec<&String>) {
+        let mut records = vec![];
+        let mut other_lines = vec![];
+
+        for line in output {
+            match Record::parse_line(line) {
+                Some(record) if record.day == day => records.push(record),
+                _ => other_lines.push(line),
+            }
+        }
+
+        (records, other_lines)
+    }
+
+    pub fn timings_from_records(records: &[Record], day: Day) -> super::Timings {
         let mut timings = super::Timings {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
         };
 
-        output
+        records
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+            .filter(|record| record.status != Status::Unsolved)
+            .for_each(|record| {
+                let timing = Some(PartTiming {
+                    stats: record.stats,
+                    memory: record.memory,
+                    status: record.status,
+                });
+
+                match record.part {
+                    0 => timings.parse = timing,
+                    1 => timings.part_1 = timing,
+                    2 => timings.part_2 = timing,
+                    _ => {}
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                // the duration of a part that timed out is just the limit.
+                if record.status == Status::Solved {
+                    #[allow(clippy::cast_precision_loss)]
+                    let nanos = record.stats.median.as_nanos() as f64;
+                    timings.total_nanos += nanos;
                 }
-
-                timings.total_nanos += nanos;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(test, feature = "test_lib"))]
     macro_rules! assert_approx_eq {
         ($a:expr, $b:expr) => {{
             let (a, b) = (&$a, &$b);
@@ -200,55 +552,102 @@ mod child_commands {
         }};
     }
 
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(test, feature = "test_lib"))]
     mod tests {
-        use super::parse_exec_time;
+        use super::{failed_output, parse_records, timings_from_records, Error};
+        use crate::template::record::{Record, Status};
+        use crate::template::stats::Stats;
 
         use crate::day;
+        use std::time::Duration;
+
+        fn record(part: u8, answer: Option<&str>, nanos: u64, samples: u128) -> String {
+            Record {
+                day: day!(1),
+                part,
+                answer: answer.map(Into::into),
+                stats: Stats {
+                    samples,
+                    ..Stats::single(Duration::from_nanos(nanos))
+                },
+                memory: None,
+                error: None,
+                status: if answer.is_some() || part == 0 {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+            }
+            .to_string()
+        }
+
+        #[test]
+        fn test_failed_output() {
+            let error = Error::IO(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "cargo not found",
+            ));
+            let output = failed_output(day!(1), &error);
+            let (records, _) = parse_records(&output.stdout, day!(1));
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].status, Status::Failed);
+            assert_eq!(records[0].error.as_deref(), Some("cargo not found"));
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            let output = [
+                record(1, Some("0"), 74, 100000),
+                record(2, Some("10"), 74130000, 99999),
+                "".into(),
+            ];
+            let (records, other_lines) = parse_records(&output, day!(1));
+            let res = timings_from_records(&records, day!(1));
+            assert_eq!(other_lines.len(), 1);
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap().stats.median, Duration::from_nanos(74));
+            assert_eq!(res.part_2.unwrap().stats.samples, 99999);
         }
 
         #[test]
         fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+            let output = [
+                record(1, Some("@ @ @ ( ) ms (2s @ 5 samples)"), 2000000000, 5),
+                record(2, Some("10s"), 100000000, 1),
+                "Part 1: 42 (1.0ms @ 10 samples)".into(),
+            ];
+            let (records, other_lines) = parse_records(&output, day!(1));
+            let res = timings_from_records(&records, day!(1));
+            assert_eq!(other_lines.len(), 1);
+            assert_eq!(
+                records[0].answer.as_deref(),
+                Some("@ @ @ ( ) ms (2s @ 5 samples)")
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap().stats.median, Duration::from_secs(2));
+            assert_eq!(res.part_2.unwrap().stats.median, Duration::from_millis(100));
+        }
+
+        #[test]
+        fn test_parse_step() {
+            let output = [record(0, None, 500, 1), record(1, Some("1"), 1000, 1)];
+            let (records, _) = parse_records(&output, day!(1));
+            let res = timings_from_records(&records, day!(1));
+            assert_approx_eq!(res.total_nanos, 1500_f64);
+            assert_eq!(res.parse.unwrap().stats.median, Duration::from_nanos(500));
+            assert!(res.part_2.is_none());
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
+            let (records, _) = parse_records(
+                &[record(1, None, 10, 1), record(2, None, 10, 1), "".into()],
                 day!(1),
             );
+            let res = timings_from_records(&records, day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert!(res.part_1.is_none());
+            assert!(res.part_2.is_none());
         }
     }
 }
@@ -0,0 +1,79 @@
+use std::process;
+
+use crate::template::benchmark_history::{self, get_path_for_history, Run};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::Year;
+
+pub fn handle(year: Year, baseline: Option<String>, threshold_percent: f64) {
+    let runs = match benchmark_history::read(year) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let Some((current, previous)) = runs.split_last() else {
+        eprintln!(
+            "No benchmark runs recorded in \"{}\" yet. Run `cargo time` first.",
+            get_path_for_history(year)
+        );
+        process::exit(1);
+    };
+
+    let baseline_run: Option<&Run> = match &baseline {
+        Some(name) => previous.iter().rev().find(|run| run.matches(name)),
+        None => previous.last(),
+    };
+
+    let Some(baseline_run) = baseline_run else {
+        match baseline {
+            Some(name) => eprintln!("Could not find a baseline run named \"{name}\"."),
+            None => eprintln!("Only one benchmark run recorded, nothing to compare against."),
+        }
+        process::exit(1);
+    };
+
+    println!("{ANSI_BOLD}Current:{ANSI_RESET}  {}", current.describe());
+    println!(
+        "{ANSI_BOLD}Baseline:{ANSI_RESET} {}",
+        baseline_run.describe()
+    );
+    println!();
+
+    let changes = benchmark_history::compare(baseline_run, current);
+    let mut regressions = 0;
+
+    if changes.is_empty() {
+        println!("The runs have no benchmarked parts in common.");
+        return;
+    }
+
+    for change in &changes {
+        let is_regression = change.is_regression(threshold_percent);
+        if is_regression {
+            regressions += 1;
+        }
+
+        println!(
+            "Day {} {:<6} {:>10} -> {:<10} {:>+8.1}%{}",
+            change.day,
+            change.part,
+            format!("{:.1?}", change.before.median),
+            format!("{:.1?}", change.after.median),
+            change.percent(),
+            if is_regression { "  ⚠ slower" } else { "" }
+        );
+    }
+
+    println!();
+
+    if regressions > 0 {
+        println!(
+            "{ANSI_BOLD}{regressions} part(s) got slower by more than {threshold_percent}%.{ANSI_RESET}"
+        );
+        process::exit(1);
+    }
+
+    println!("No part got slower by more than {threshold_percent}%.");
+}
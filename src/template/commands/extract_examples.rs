@@ -0,0 +1,324 @@
+use std::io::{self, Write};
+use std::{fs, process};
+
+use crate::template::aoc_client::get_path_for_puzzle;
+use crate::template::examples::get_path_for_manifest;
+use crate::template::{get_data_dir, toml, ANSI_BOLD, ANSI_RESET};
+use crate::{Day, Year};
+
+/// Lines of each example that the preview shows.
+const PREVIEW_LINES: usize = 5;
+
+/// A code block of a puzzle description and the answers it is highlighted with, if any.
+#[derive(Debug, PartialEq, Eq)]
+struct Candidate {
+    input: String,
+    answers: [Option<String>; 2],
+}
+
+/// Finds the code blocks of a puzzle description and guesses the expected answers of the examples among them.
+///
+/// The heuristic follows how puzzles are written: the answer of a part is the last highlighted code span (`*`42`*`)
+/// of its description, and it belongs to the last code block before it. If part two has no code block of its own,
+/// its answer belongs to the first example of part one.
+fn find_candidates(puzzle: &str) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = vec![];
+    // the last answer of each part and the index of the code block before it.
+    let mut answers: [Option<(String, Option<usize>)>; 2] = [None, None];
+    let mut part = 0;
+    let mut current = None;
+    let mut lines = puzzle.lines();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("## ") && line.contains("Part Two") {
+            part = 1;
+            current = None;
+        } else if line.starts_with("```") {
+            let mut input = String::new();
+            for line in lines.by_ref().take_while(|line| !line.starts_with("```")) {
+                input.push_str(line);
+                input.push('\n');
+            }
+
+            // part two often repeats the example of part one.
+            current = match candidates.iter().position(|c| c.input == input) {
+                Some(index) => Some(index),
+                None => {
+                    candidates.push(Candidate {
+                        input,
+                        answers: [None, None],
+                    });
+                    Some(candidates.len() - 1)
+                }
+            };
+        } else if let Some(answer) = highlighted_spans(line).pop() {
+            answers[part] = Some((answer, current));
+        }
+    }
+
+    for (part, answer) in answers.into_iter().enumerate() {
+        if let Some((answer, index)) = answer {
+            if let Some(candidate) = candidates.get_mut(index.unwrap_or(0)) {
+                candidate.answers[part] = Some(answer);
+            }
+        }
+    }
+
+    candidates
+}
+
+/// The contents of the highlighted code spans of a line, i.e. `*`42`*`.
+fn highlighted_spans(line: &str) -> Vec<String> {
+    let mut spans = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find("*`") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("`*") else {
+            break;
+        };
+        spans.push(rest[..end].trim().to_string());
+        rest = &rest[end + 2..];
+    }
+
+    spans
+}
+
+/// An example that is written: its name, the folder of its input and the candidate it was found in.
+struct Example<'a> {
+    name: char,
+    folder: String,
+    candidate: &'a Candidate,
+}
+
+/// The examples to write: all candidates with an answer, or the first code block if none has one.
+fn select_examples(candidates: &[Candidate]) -> Vec<Example<'_>> {
+    let with_answers: Vec<&Candidate> = candidates
+        .iter()
+        .filter(|candidate| candidate.answers.iter().any(Option::is_some))
+        .collect();
+
+    let selected = if with_answers.is_empty() {
+        candidates.iter().take(1).collect()
+    } else {
+        with_answers
+    };
+
+    selected
+        .into_iter()
+        .zip('a'..='z')
+        .map(|(candidate, name)| Example {
+            name,
+            // the first example lives where the tests of the scaffold expect it.
+            folder: match name {
+                'a' => "examples".into(),
+                name => format!("examples_{name}"),
+            },
+            candidate,
+        })
+        .collect()
+}
+
+fn to_manifest(examples: &[Example], day: Day) -> String {
+    let mut manifest = format!("# Examples of day {day}, extracted by `cargo extract-examples`.\n");
+
+    for example in examples {
+        manifest.push_str(&format!(
+            "\n[{}]\nfile = {}\n",
+            example.name,
+            toml::string(&format!("{}/{day}.txt", example.folder))
+        ));
+
+        for (part, answer) in example.candidate.answers.iter().enumerate() {
+            let Some(answer) = answer else {
+                continue;
+            };
+            let is_integer = answer
+                .trim_start_matches('-')
+                .chars()
+                .all(|c| c.is_ascii_digit())
+                && !answer.trim_start_matches('-').is_empty();
+            let value = if is_integer {
+                answer.clone()
+            } else {
+                toml::string(answer)
+            };
+            manifest.push_str(&format!("part_{} = {value}\n", part + 1));
+        }
+    }
+
+    manifest
+}
+
+/// Extracts the examples of a stored puzzle description into example files and the example manifest of the day.
+/// The files are only written after a preview was confirmed, or with `yes`.
+pub fn handle(year: Year, day: Day, yes: bool) {
+    let puzzle_path = get_path_for_puzzle(year, day);
+    let puzzle = fs::read_to_string(&puzzle_path).unwrap_or_else(|e| {
+        eprintln!("Could not read \"{puzzle_path}\": {e}. Run `cargo download {day}` first.");
+        process::exit(1);
+    });
+
+    let candidates = find_candidates(&puzzle);
+    if candidates.is_empty() {
+        println!("No code blocks found in \"{puzzle_path}\".");
+        return;
+    }
+
+    let examples = select_examples(&candidates);
+    let data_dir = get_data_dir(year);
+    let manifest_path = get_path_for_manifest(year, day);
+    let manifest = to_manifest(&examples, day);
+
+    let mut files: Vec<(String, String)> = vec![];
+
+    println!(
+        "Found {} code block(s) in \"{puzzle_path}\".\n",
+        candidates.len()
+    );
+
+    for example in &examples {
+        let path = data_dir
+            .join(&example.folder)
+            .join(format!("{day}.txt"))
+            .display()
+            .to_string();
+
+        let answers: Vec<String> = example
+            .candidate
+            .answers
+            .iter()
+            .enumerate()
+            .filter_map(|(part, answer)| Some(format!("part {}: {}", part + 1, answer.as_ref()?)))
+            .collect();
+        let answers = if answers.is_empty() {
+            "no answers".to_string()
+        } else {
+            answers.join(", ")
+        };
+
+        println!(
+            "{ANSI_BOLD}Example {}{ANSI_RESET} → {path}{} ({answers})",
+            example.name,
+            overwrite_note(&path)
+        );
+
+        let lines: Vec<&str> = example.candidate.input.lines().collect();
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("  {line}");
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("  … {} more line(s)", lines.len() - PREVIEW_LINES);
+        }
+        println!();
+
+        files.push((path, example.candidate.input.clone()));
+    }
+
+    let skipped = candidates.len() - examples.len();
+    if skipped > 0 {
+        println!("Skipped {skipped} other code block(s) without a highlighted answer.\n");
+    }
+
+    println!(
+        "{ANSI_BOLD}Manifest{ANSI_RESET} → {manifest_path}{}",
+        overwrite_note(&manifest_path)
+    );
+    for line in manifest.lines() {
+        println!("{}", format!("  {line}").trim_end());
+    }
+    println!();
+
+    if !yes && !confirm("Write these files?") {
+        println!("Nothing written.");
+        return;
+    }
+
+    files.push((manifest_path, manifest));
+
+    for (path, content) in files {
+        let result = std::path::Path::new(&path)
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&path, content));
+
+        match result {
+            Ok(()) => println!("Wrote \"{path}\"."),
+            Err(e) => {
+                eprintln!("Failed to write \"{path}\": {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn overwrite_note(path: &str) -> &'static str {
+    if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        " (overwrites the existing file)"
+    } else {
+        ""
+    }
+}
+
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{find_candidates, select_examples, to_manifest, Candidate};
+    use crate::day;
+
+    #[test]
+    fn finds_examples_of_part_one() {
+        let candidates = find_candidates(include_str!("../fixtures/puzzle_part_one.md"));
+        assert_eq!(
+            candidates,
+            vec![Candidate {
+                input: "3   4\n4   3\n2   5\n".into(),
+                answers: [Some("5".into()), None],
+            }]
+        );
+    }
+
+    #[test]
+    fn finds_examples_of_both_parts() {
+        let candidates = find_candidates(include_str!("../fixtures/puzzle_both_parts.md"));
+        assert_eq!(
+            candidates,
+            vec![
+                Candidate {
+                    input: "Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 green\n".into(),
+                    answers: [Some("1".into()), None],
+                },
+                Candidate {
+                    input: "Game 1: 3 * 4 = 12\nGame 2: 1 * 2 = 2\n".into(),
+                    answers: [None, Some("14".into())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reuses_examples_of_part_one() {
+        let puzzle = "## --- Day 1 ---\n\n```\n1\n2\n```\n\nThe answer is *`3`*.\n\n## --- Part Two ---\n\n```\nnot an example\n```\n\n```\n1\n2\n```\n\nNow it is *`6`*.\n";
+        let candidates = find_candidates(puzzle);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].answers, [Some("3".into()), Some("6".into())]);
+        assert_eq!(select_examples(&candidates).len(), 1);
+    }
+
+    #[test]
+    fn writes_manifests() {
+        let candidates = find_candidates(include_str!("../fixtures/puzzle_both_parts.md"));
+        assert_eq!(
+            to_manifest(&select_examples(&candidates), day!(2)),
+            "# Examples of day 02, extracted by `cargo extract-examples`.\n\n[a]\nfile = \"examples/02.txt\"\npart_1 = 1\n\n[b]\nfile = \"examples_b/02.txt\"\npart_2 = 14\n"
+        );
+    }
+}
@@ -1,5 +1,10 @@
 pub mod all;
+pub mod bench_compare;
 pub mod download;
+pub mod extract_examples;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod verify;
+pub mod watch;
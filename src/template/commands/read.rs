@@ -1,16 +1,24 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client;
+use crate::{Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+/// Prints the description of a puzzle. With `offline`, the stored description is printed without fetching it.
+pub fn handle(year: Year, day: Day, wait: bool, offline: bool) {
+    if offline {
+        let path = aoc_client::get_path_for_puzzle(year, day);
+        match fs::read_to_string(&path) {
+            Ok(puzzle) => println!("{puzzle}"),
+            Err(e) => {
+                eprintln!("could not read \"{path}\": {e}. Run `cargo download {day}` first.");
+                process::exit(1);
+            }
+        }
+        return;
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = aoc_client::read(year, day, wait) {
+        eprintln!("failed to read day {day}: {e}");
         process::exit(1);
     };
 }
@@ -1,94 +1,333 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::examples::get_path_for_manifest;
+use crate::template::{get_bin_name, get_data_dir};
+use crate::{Day, Year};
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// The template that `cargo scaffold` uses if no other template is chosen and the project does not define one.
+const DEFAULT_TEMPLATE: &str = r#"advent_of_code::solution!({{year}}, {{day}});
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Option<{{answer_type}}> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
     None
 }
+"#;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// A solution that parses its input into a grid of bytes once.
+const GRID_TEMPLATE: &str = r#"advent_of_code::solution!({{year}}, {{day}}, parse -> Grid);
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+type Grid = Vec<Vec<u8>>;
+
+fn parse(input: &str) -> Grid {
+    input.lines().map(|line| line.bytes().collect()).collect()
+}
+
+pub fn part_one(grid: &Grid) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(grid: &Grid) -> Option<{{answer_type}}> {
+    None
+}
+"#;
+
+/// A solution that parses its input line by line with `nom`.
+const NOM_TEMPLATE: &str = r#"use nom::{
+    character::complete::{line_ending, not_line_ending},
+    multi::separated_list1,
+    IResult,
+};
+
+advent_of_code::solution!({{year}}, {{day}}, parse -> Vec<&'a str>);
+
+fn lines(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(line_ending, not_line_ending)(input)
+}
+
+fn parse(input: &str) -> Vec<&str> {
+    let (_, lines) = lines(input.trim_end()).expect("failed to parse input");
+    lines
+}
+
+pub fn part_one(lines: &[&str]) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(lines: &[&str]) -> Option<{{answer_type}}> {
+    None
+}
+"#;
+
+/// The templates that ship with this project, by the name that `--template` selects them with.
+const BUILTIN_TEMPLATES: [(&str, &str); 3] = [
+    ("default", DEFAULT_TEMPLATE),
+    ("grid", GRID_TEMPLATE),
+    ("nom", NOM_TEMPLATE),
+];
+
+/// The template of the project, used instead of [`DEFAULT_TEMPLATE`] if it exists.
+const PROJECT_TEMPLATE_PATH: &str = ".aoc/template.rs";
+
+/// Named templates of the project, e.g. `.aoc/templates/grid.rs` for `--template grid`. They take precedence over
+/// the built-in templates of the same name.
+const PROJECT_TEMPLATES_DIR: &str = ".aoc/templates";
+
+/// The answer type that templates are rendered with if `--answer-type` is not passed.
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+/// Loads the template with the given name, or the default template of the project.
+fn load_template(name: Option<&str>) -> Result<String, String> {
+    let Some(name) = name else {
+        return match fs::read_to_string(PROJECT_TEMPLATE_PATH) {
+            Ok(template) => Ok(template),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(DEFAULT_TEMPLATE.to_string()),
+            Err(e) => Err(format!("could not read \"{PROJECT_TEMPLATE_PATH}\": {e}")),
+        };
+    };
+
+    let path = Path::new(PROJECT_TEMPLATES_DIR).join(format!("{name}.rs"));
+    match fs::read_to_string(&path) {
+        Ok(template) => return Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(format!("could not read \"{}\": {e}", path.display())),
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    if let Some((_, template)) = BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+    {
+        return Ok((*template).to_string());
+    }
+
+    let mut available: Vec<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(builtin, _)| (*builtin).to_string())
+        .collect();
+    if let Ok(entries) = fs::read_dir(PROJECT_TEMPLATES_DIR) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "rs") {
+                if let Some(stem) = path.file_stem() {
+                    available.push(stem.to_string_lossy().into_owned());
+                }
+            }
+        }
     }
+    available.sort();
+    available.dedup();
+
+    Err(format!(
+        "unknown template \"{name}\", available templates: {}",
+        available.join(", ")
+    ))
+}
+
+/// Replaces the placeholders of a template:
+///
+/// - `{{year}}`: the year, e.g. `2023`.
+/// - `{{day}}`: the day without padding, e.g. `1`.
+/// - `{{day_padded}}`: the day padded to two digits, e.g. `01`.
+/// - `{{answer_type}}`: the type of the answers of both parts, e.g. `u32`.
+fn render(template: &str, year: Year, day: Day, answer_type: &str) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.into_inner().to_string())
+        .replace("{{day_padded}}", &day.to_string())
+        .replace("{{answer_type}}", answer_type)
 }
-"#;
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Creates an empty data file unless it exists already.
+fn create_data_file(path: &str, kind: &str) {
+    match safe_create_file(path) {
+        Ok(_) => println!("Created empty {kind} file \"{path}\""),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            println!("Skipped {kind} file \"{path}\", it already exists.");
+        }
+        Err(e) => {
+            eprintln!("Failed to create {kind} file: {e}");
+            process::exit(1);
+        }
+    }
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// The example manifest of a new day, with the example files that scaffold creates: `a` in `examples` and, with
+/// `part_two_example`, `b` in `examples_b`.
+fn manifest(day: Day, part_two_example: bool) -> String {
+    let mut manifest = format!(
+        "# Examples of day {day} with their expected answers, checked by `cargo test` and `cargo solve {} --examples`.\n# Add `part_1 = <answer>` or `part_2 = <answer>` to an example to check its answers.\n\n[a]\nfile = \"examples/{day}.txt\"\n",
+        day.into_inner()
+    );
+    if part_two_example {
+        manifest.push_str(&format!("\n[b]\nfile = \"examples_b/{day}.txt\"\n"));
+    }
+    manifest
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+/// The first free backup path of a module, i.e. `src/bin/2023-01.rs.bak`, then `src/bin/2023-01.rs.bak.1` and so on.
+fn backup_path(module_path: &str) -> String {
+    let mut path = format!("{module_path}.bak");
+    let mut i = 1;
+    while Path::new(&path).exists() {
+        path = format!("{module_path}.bak.{i}");
+        i += 1;
+    }
+    path
+}
+
+/// Writes a generated file of a day. Files that exist already are skipped, with `force` they are backed up and
+/// regenerated. `content` is only called if the file is written.
+fn create_generated_file(path: &str, kind: &str, force: bool, content: impl FnOnce() -> String) {
+    let exists = Path::new(path).exists();
+    if exists && !force {
+        println!(
+            "Skipped {kind} file \"{path}\", it already exists. Pass --force to regenerate it."
+        );
+        return;
+    }
+
+    let content = content();
+
+    if exists {
+        let backup_path = backup_path(path);
+        if let Err(e) = fs::copy(path, &backup_path) {
+            eprintln!("Failed to back up {kind} file: {e}");
             process::exit(1);
         }
-    };
+        println!("Backed up {kind} file to \"{backup_path}\"");
+    }
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match fs::write(path, content) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created {kind} file \"{path}\"");
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to write {kind} file: {e}");
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+/// Creates the module, input and example files and the example manifest of a day. Files that exist already are
+/// skipped, with `force` the module and the manifest are regenerated after backing them up. With
+/// `part_two_example`, part two gets an example file of its own in `examples_b`, listed as example `b`.
+pub fn handle(
+    year: Year,
+    day: Day,
+    template: Option<&str>,
+    answer_type: &str,
+    part_two_example: bool,
+    force: bool,
+) {
+    let data_dir = get_data_dir(year);
+    let module_path = format!("src/bin/{}.rs", get_bin_name(year, day));
+    let mut folders = vec!["inputs", "examples"];
+    if part_two_example {
+        folders.push("examples_b");
+    }
+    for folder in &folders {
+        if let Err(e) = fs::create_dir_all(data_dir.join(folder)) {
+            eprintln!("Failed to create data folder: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    create_generated_file(&module_path, "module", force, || {
+        let template = match load_template(template) {
+            Ok(template) => template,
+            Err(e) => {
+                eprintln!("Failed to load template: {e}");
+                process::exit(1);
+            }
+        };
+        let module = render(&template, year, day, answer_type);
+        if module.contains("{{") {
+            eprintln!("Warning: the template contains unknown placeholders, only {{{{year}}}}, {{{{day}}}}, {{{{day_padded}}}} and {{{{answer_type}}}} are replaced.");
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+        module
+    });
+
+    for (folder, kind) in [
+        ("inputs", "input"),
+        ("examples", "example"),
+        ("examples_b", "part two example"),
+    ] {
+        if folders.contains(&folder) {
+            let path = data_dir.join(folder).join(format!("{day}.txt"));
+            create_data_file(&path.display().to_string(), kind);
         }
     }
 
+    create_generated_file(
+        &get_path_for_manifest(year, day),
+        "example manifest",
+        force,
+        || manifest(day, part_two_example),
+    );
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `cargo solve {} --year {year}` to run your solution.",
+        day
+    );
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{manifest, render, BUILTIN_TEMPLATES};
+    use crate::template::toml;
+    use crate::{day, year};
+
+    #[test]
+    fn writes_manifests() {
+        let tables = toml::parse_tables(&manifest(day!(7), false)).unwrap();
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[1].name.as_deref(), Some("a"));
+        assert_eq!(
+            tables[1].pairs,
+            vec![("file".to_string(), "examples/07.txt".to_string())]
+        );
+
+        let tables = toml::parse_tables(&manifest(day!(7), true)).unwrap();
+        assert_eq!(tables[2].name.as_deref(), Some("b"));
+        assert_eq!(
+            tables[2].pairs,
+            vec![("file".to_string(), "examples_b/07.txt".to_string())]
+        );
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let template =
+            "solution!({{year}}, {{day}}); // day {{day_padded}}: Option<{{answer_type}}>";
+        assert_eq!(
+            render(template, year!(2023), day!(7), "u64"),
+            "solution!(2023, 7); // day 07: Option<u64>"
+        );
+    }
+
+    #[test]
+    fn renders_builtin_templates() {
+        for (name, template) in BUILTIN_TEMPLATES {
+            let module = render(template, year!(2023), day!(1), "u32");
+            assert!(
+                module.starts_with("advent_of_code::solution!(2023, 1")
+                    || module.contains("\nadvent_of_code::solution!(2023, 1"),
+                "{name}"
+            );
+            assert!(!module.contains("{{"), "{name}");
+            assert!(!module.contains("mod tests"), "{name}");
+        }
+    }
 }
@@ -1,31 +1,93 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::Day;
+use crate::template::examples;
+use crate::template::get_bin_name;
+use crate::template::registry::{self, Entry};
+use crate::template::runner::RunOptions;
+use crate::template::OutputFormat;
+use crate::{Day, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Runs a solution in-process if possible, otherwise via `cargo run`.
+pub fn handle(solutions: &[Entry], year: Year, day: Day, release: bool, options: &RunOptions) {
+    if registry::can_run_in_process(release, options.mem) {
+        if let Some(solution) = registry::find(solutions, year, day) {
+            if options.examples {
+                if !examples::run(solution, options) {
+                    process::exit(1);
+                }
+                return;
+            }
+
+            let input = registry::read_input(year, day, options);
+            let records = (solution.run)(&input, options);
+
+            if records.iter().any(|record| record.status.is_failure()) {
+                process::exit(1);
+            }
+            return;
+        }
+    }
+
+    let mut cmd = Command::new("cargo")
+        .args(cargo_args(year, day, release, options))
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// The arguments of `cargo` to build and run a solution binary with the given options.
+pub(crate) fn cargo_args(year: Year, day: Day, release: bool, options: &RunOptions) -> Vec<String> {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    if options.mem {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("mem".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
+    if options.time {
         cmd_args.push("--time".to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    if options.mem {
+        cmd_args.push("--mem".to_string());
+    }
+
+    if let Some(timeout) = options.timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs().to_string());
+    }
+
+    cmd_args.extend(options.input.to_args());
+
+    if options.examples {
+        cmd_args.push("--examples".to_string());
+    }
+
+    if options.format == OutputFormat::Json {
+        cmd_args.push("--format".to_string());
+        cmd_args.push("json".to_string());
+    }
 
-    cmd.wait().unwrap();
+    cmd_args
 }
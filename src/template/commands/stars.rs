@@ -0,0 +1,18 @@
+use std::process;
+
+use crate::template::readme_stars;
+use crate::Year;
+
+/// Rebuilds the stars table of the readme from the accepted answers and the submission ledger.
+pub fn handle(year: Year) {
+    match readme_stars::update(year) {
+        Ok(stars) => {
+            let count: usize = stars.iter().map(readme_stars::Stars::count).sum();
+            println!("Successfully updated README with {count} star(s) of {year}.");
+        }
+        Err(e) => {
+            eprintln!("Failed to update README with stars: {e}");
+            process::exit(1);
+        }
+    }
+}
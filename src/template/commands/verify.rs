@@ -0,0 +1,154 @@
+use std::path::Path;
+use std::process;
+
+use crate::template::answers::{self, get_path_for_answers, Answers};
+use crate::template::commands::all;
+use crate::template::readme_benchmarks::get_path_for_bin;
+use crate::template::record::{Record, Status};
+use crate::template::registry::{self, Entry};
+use crate::template::runner::RunOptions;
+use crate::template::{try_read_file, ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day, Year};
+
+/// Outcome of checking one part against its known answer.
+#[derive(Debug, PartialEq)]
+enum Check {
+    Pass,
+    Fail,
+    Missing,
+}
+
+/// Re-runs solutions and compares their answers with the accepted answers in `data/YYYY/answers`.
+/// Exits with a non-zero status if any answer does not match.
+pub fn handle(solutions: &[Entry], year: Year, day: Option<Day>) {
+    let is_single_day = day.is_some();
+    let days: Vec<Day> = match day {
+        Some(day) => vec![day],
+        None => all_days().collect(),
+    };
+
+    let options = RunOptions {
+        quiet: true,
+        ..RunOptions::default()
+    };
+
+    let mut failures = 0;
+
+    for day in days {
+        let answers = match answers::read(year, day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}: {e}", get_path_for_answers(year, day));
+                process::exit(1);
+            }
+        };
+
+        let solution = registry::find(solutions, year, day);
+
+        if solution.is_none() && !Path::new(&get_path_for_bin(year, day)).exists() {
+            // only complain about missing solutions if they were asked for or have known answers.
+            if is_single_day || answers != Answers::default() {
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: not solved.");
+            }
+            continue;
+        }
+
+        let input = match try_read_file("inputs", year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                if is_single_day || answers != Answers::default() {
+                    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: could not open input file: {e}");
+                }
+                continue;
+            }
+        };
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+        // without the `in_process` feature, solutions run as their own binaries in the profile of this binary.
+        let records = match solution {
+            Some(solution) => (solution.run)(&input, &options),
+            None => all::run_bin(year, day, cfg!(not(debug_assertions)), &options),
+        };
+
+        for part in [1, 2] {
+            let record = records.iter().find(|record| record.part == part);
+            let (check, line) = check_part(part, record, answers.get(part));
+            if check == Check::Fail {
+                failures += 1;
+            }
+            println!("{line}");
+        }
+    }
+
+    println!();
+
+    if failures > 0 {
+        println!("{ANSI_BOLD}{failures} part(s) do not match their accepted answer.{ANSI_RESET}");
+        process::exit(1);
+    }
+
+    println!("All known answers match.");
+}
+
+/// Compares the record of a part with its expected answer and formats the result as a line.
+fn check_part(part: u8, record: Option<&Record>, expected: Option<&str>) -> (Check, String) {
+    let actual = record.and_then(|record| match record.status {
+        Status::Solved => record.answer.clone(),
+        _ => None,
+    });
+
+    let got = match (record, &actual) {
+        (_, Some(answer)) => answer.clone(),
+        (Some(record), None) if record.status.is_failure() => {
+            record.error.clone().unwrap_or_else(|| "failed".into())
+        }
+        _ => "no answer".into(),
+    };
+
+    match (expected, actual.as_deref()) {
+        (Some(expected), Some(actual)) if expected == actual => {
+            (Check::Pass, format!("Part {part}: ✔ {actual}"))
+        }
+        (Some(expected), _) => (
+            Check::Fail,
+            format!("Part {part}: ✖ expected {expected}, got {got}"),
+        ),
+        (None, _) => (
+            Check::Missing,
+            format!("Part {part}: ? no accepted answer (got {got})"),
+        ),
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{check_part, Check};
+    use crate::day;
+    use crate::template::record::{Record, Status};
+    use crate::template::stats::Stats;
+
+    fn record(answer: Option<&str>, status: Status) -> Record {
+        Record {
+            day: day!(1),
+            part: 1,
+            answer: answer.map(Into::into),
+            stats: Stats::default(),
+            memory: None,
+            error: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn checks_parts() {
+        let solved = record(Some("142"), Status::Solved);
+        assert_eq!(check_part(1, Some(&solved), Some("142")).0, Check::Pass);
+        assert_eq!(check_part(1, Some(&solved), Some("143")).0, Check::Fail);
+        assert_eq!(check_part(1, Some(&solved), None).0, Check::Missing);
+
+        let unsolved = record(None, Status::Unsolved);
+        assert_eq!(check_part(1, Some(&unsolved), Some("142")).0, Check::Fail);
+        assert_eq!(check_part(1, None, Some("142")).0, Check::Fail);
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::{Duration, Instant, SystemTime};
+use std::{fs, thread};
+
+use crate::template::commands::solve::cargo_args;
+use crate::template::examples::get_path_for_manifest;
+use crate::template::input::InputSource;
+use crate::template::runner::RunOptions;
+use crate::template::{get_bin_name, get_data_dir, toml, ANSI_BOLD, ANSI_RESET};
+use crate::{Day, Year};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// A change is only acted upon once no file changed for this long, e.g. while an editor saves several files.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Modification times of the watched files, [`None`] if a file does not exist (yet).
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Re-runs the examples and then the input of a day whenever its solution, the library sources or its data change.
+/// Changes are detected by polling file metadata, so this works on any platform.
+pub fn handle(year: Year, day: Day, release: bool, options: &RunOptions) {
+    if options.submit.is_some() {
+        eprintln!("--submit cannot be used with --watch.");
+        process::exit(1);
+    }
+
+    if options.input == InputSource::Stdin {
+        eprintln!("--stdin cannot be used with --watch.");
+        process::exit(1);
+    }
+
+    let mut snapshot = take_snapshot(year, day, options);
+    let mut changed = vec![];
+
+    for run in 1.. {
+        print!("{ANSI_CLEAR}");
+        println!("{ANSI_BOLD}Day {day} of {year}{ANSI_RESET} · run #{run}");
+        if !changed.is_empty() {
+            let paths: Vec<String> = changed
+                .iter()
+                .map(|path: &PathBuf| path.display().to_string())
+                .collect();
+            println!("changed: {}", paths.join(", "));
+        }
+        println!();
+
+        let timer = Instant::now();
+        let is_success = run_day(year, day, release, options);
+        let elapsed = timer.elapsed();
+
+        println!();
+        if is_success {
+            println!("{ANSI_BOLD}✔ finished in {elapsed:.1?}{ANSI_RESET} · watching for changes, press Ctrl-C to stop.");
+        } else {
+            println!("{ANSI_BOLD}✖ failed after {elapsed:.1?}{ANSI_RESET} · watching for changes, press Ctrl-C to stop.");
+        }
+
+        changed = wait_for_changes(year, day, options, &mut snapshot);
+    }
+}
+
+/// Rebuilds the solution and runs its examples, then its input. Returns whether both runs succeeded.
+fn run_day(year: Year, day: Day, release: bool, options: &RunOptions) -> bool {
+    println!("{ANSI_BOLD}Examples{ANSI_RESET}");
+    let examples = RunOptions {
+        examples: true,
+        ..options.clone()
+    };
+    let are_examples_ok = run_cargo(year, day, release, &examples);
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Input{ANSI_RESET} ({})",
+        options.input.describe(year, day)
+    );
+    let is_input_ok = run_cargo(year, day, release, options);
+
+    are_examples_ok && is_input_ok
+}
+
+fn run_cargo(year: Year, day: Day, release: bool, options: &RunOptions) -> bool {
+    let mut args = cargo_args(year, day, release, options);
+    // hide the progress of cargo, build errors are still shown.
+    args.insert(1, "--quiet".into());
+
+    Command::new("cargo")
+        .args(args)
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Polls the watched files until they change and then do not change for [`DEBOUNCE`].
+/// Updates the snapshot and returns the paths that changed.
+fn wait_for_changes(
+    year: Year,
+    day: Day,
+    options: &RunOptions,
+    snapshot: &mut Snapshot,
+) -> Vec<PathBuf> {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let mut latest = take_snapshot(year, day, options);
+        if latest == *snapshot {
+            continue;
+        }
+
+        loop {
+            thread::sleep(DEBOUNCE);
+            let next = take_snapshot(year, day, options);
+            if next == latest {
+                break;
+            }
+            latest = next;
+        }
+
+        let changed = changed_paths(snapshot, &latest);
+        *snapshot = latest;
+        return changed;
+    }
+}
+
+fn changed_paths(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = before
+        .keys()
+        .chain(after.keys())
+        .filter(|path| before.get(*path) != after.get(*path))
+        .cloned()
+        .collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+fn take_snapshot(year: Year, day: Day, options: &RunOptions) -> Snapshot {
+    watched_paths(year, day, options)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// The solution of the day, the library sources, the input and all example files of the day.
+fn watched_paths(year: Year, day: Day, options: &RunOptions) -> Vec<PathBuf> {
+    let data_dir = get_data_dir(year);
+    let mut paths = vec![
+        PathBuf::from(format!("src/bin/{}.rs", get_bin_name(year, day))),
+        PathBuf::from("Cargo.toml"),
+        PathBuf::from(get_path_for_manifest(year, day)),
+    ];
+
+    let mut library = vec![];
+    find_sources(Path::new("src"), &mut library);
+    paths.extend(library);
+
+    match &options.input {
+        InputSource::File(path) => paths.push(path.clone()),
+        _ => paths.push(data_dir.join("inputs").join(format!("{day}.txt"))),
+    }
+
+    // examples live in `data/YYYY/examples` and folders like `data/YYYY/examples_b`.
+    if let Ok(entries) = fs::read_dir(&data_dir) {
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with("examples") {
+                paths.push(entry.path().join(format!("{day}.txt")));
+            }
+        }
+    }
+
+    // files that the example manifest refers to.
+    let manifest = fs::read_to_string(get_path_for_manifest(year, day)).unwrap_or_default();
+    if let Ok(tables) = toml::parse_tables(&manifest) {
+        for (key, value) in tables.into_iter().flat_map(|table| table.pairs) {
+            if key == "file" {
+                paths.push(data_dir.join(value));
+            }
+        }
+    }
+
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Collects the `.rs` files in a directory and its subdirectories, except for the solutions in `src/bin`.
+fn find_sources(dir: &Path, sources: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if path != Path::new("src/bin") {
+                find_sources(&path, sources);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            sources.push(path);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{changed_paths, Snapshot};
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn finds_changed_paths() {
+        let time = |secs| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        let before: Snapshot = [
+            (PathBuf::from("src/bin/2023-01.rs"), time(1)),
+            (PathBuf::from("data/2023/inputs/01.txt"), None),
+            (PathBuf::from("src/lib.rs"), time(1)),
+        ]
+        .into();
+        let after: Snapshot = [
+            (PathBuf::from("src/bin/2023-01.rs"), time(2)),
+            (PathBuf::from("data/2023/inputs/01.txt"), time(2)),
+            (PathBuf::from("src/lib.rs"), time(1)),
+        ]
+        .into();
+
+        assert_eq!(
+            changed_paths(&before, &after),
+            vec![
+                PathBuf::from("data/2023/inputs/01.txt"),
+                PathBuf::from("src/bin/2023-01.rs")
+            ]
+        );
+        assert!(changed_paths(&after, &after).is_empty());
+    }
+}
@@ -0,0 +1,351 @@
+/// Manifest of the example inputs of a day and their expected answers in `data/YYYY/examples/NN.toml`.
+/// Every table is one example: its input is either inline or read from a file relative to `data/YYYY`.
+///
+/// ```toml
+/// [a]
+/// file = "examples/01.txt"
+/// part_1 = 142
+///
+/// [b]
+/// input = """
+/// two1nine
+/// """
+/// part_2 = 29
+/// ```
+///
+/// `build.rs` generates one test per example for each solution, see [`check`].
+use std::fmt::Display;
+use std::{fs, io, process};
+
+use crate::template::record::{Record, Status};
+use crate::template::registry::Entry;
+use crate::template::runner::RunOptions;
+use crate::template::solution::{PartOutput, Solution};
+use crate::template::{get_data_dir, toml, ANSI_BOLD, ANSI_RESET};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse examples: {e}"),
+            Error::IO(e) => write!(f, "could not read examples: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path_for_manifest(year: Year, day: Day) -> String {
+    get_data_dir(year)
+        .join("examples")
+        .join(format!("{day}.toml"))
+        .display()
+        .to_string()
+}
+
+/// Parses a manifest. `read_file` reads the files that inputs refer to.
+fn parse(
+    content: &str,
+    read_file: impl Fn(&str) -> io::Result<String>,
+) -> Result<Vec<Example>, Error> {
+    let tables = toml::parse_tables(content).map_err(|e| Error::Parser(e.to_string()))?;
+    let mut examples = vec![];
+
+    for table in tables {
+        let Some(name) = table.name else {
+            if let Some((key, _)) = table.pairs.first() {
+                return Err(Error::Parser(format!(
+                    "\"{key}\" must belong to an example"
+                )));
+            }
+            continue;
+        };
+
+        let mut input = None;
+        let mut part_1 = None;
+        let mut part_2 = None;
+
+        for (key, value) in table.pairs {
+            match key.as_str() {
+                "input" => input = Some(value),
+                "file" => input = Some(read_file(&value)?),
+                "part_1" => part_1 = Some(value),
+                "part_2" => part_2 = Some(value),
+                key => {
+                    return Err(Error::Parser(format!(
+                        "unknown key \"{key}\" in example \"{name}\""
+                    )))
+                }
+            }
+        }
+
+        let input = input.ok_or_else(|| {
+            Error::Parser(format!(
+                "example \"{name}\" needs an \"input\" or a \"file\""
+            ))
+        })?;
+
+        examples.push(Example {
+            name,
+            input,
+            part_1,
+            part_2,
+        });
+    }
+
+    Ok(examples)
+}
+
+/// Reads the examples of a day. A missing manifest means that there are no examples.
+pub fn read(year: Year, day: Day) -> Result<Vec<Example>, Error> {
+    match fs::read_to_string(get_path_for_manifest(year, day)) {
+        Ok(content) => parse(&content, |path| {
+            fs::read_to_string(get_data_dir(year).join(path))
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Solves an example and asserts that the answers match the expected ones of the manifest.
+/// This is called by the tests that `build.rs` generates for each example.
+pub fn check<S: Solution>(name: &str) {
+    let path = get_path_for_manifest(S::YEAR, S::DAY);
+    let examples = read(S::YEAR, S::DAY).unwrap_or_else(|e| panic!("{path}: {e}"));
+    let example = examples
+        .iter()
+        .find(|example| example.name == name)
+        .unwrap_or_else(|| panic!("no example \"{name}\" in {path}"));
+
+    if let Some(expected) = example.expected(1) {
+        let answer = S::solve_part_one(&example.input).into_answer();
+        assert_eq!(
+            answer.map(|answer| answer.map(|answer| answer.to_string())),
+            Ok(Some(expected.to_string())),
+            "part 1 of example \"{name}\""
+        );
+    }
+
+    if let Some(expected) = example.expected(2) {
+        let answer = S::solve_part_two(&example.input).into_answer();
+        assert_eq!(
+            answer.map(|answer| answer.map(|answer| answer.to_string())),
+            Ok(Some(expected.to_string())),
+            "part 2 of example \"{name}\""
+        );
+    }
+}
+
+/// The options that examples run with: quietly and without ever submitting an answer.
+fn example_options(options: &RunOptions) -> RunOptions {
+    RunOptions {
+        quiet: true,
+        submit: None,
+        ..options.clone()
+    }
+}
+
+/// Runs all examples of a solution and prints a grid of the parts that match their expected answers.
+/// Returns whether all expected answers match.
+pub fn run(solution: &Entry, options: &RunOptions) -> bool {
+    let path = get_path_for_manifest(solution.year, solution.day);
+    let examples = read(solution.year, solution.day).unwrap_or_else(|e| {
+        eprintln!("{path}: {e}");
+        process::exit(1);
+    });
+
+    if examples.is_empty() {
+        println!("No examples in {path}.");
+        return true;
+    }
+
+    let options = example_options(options);
+
+    let mut rows = vec![[
+        "Example".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+    ]];
+    let mut failures = 0;
+
+    for example in &examples {
+        let records = (solution.run)(&example.input, &options);
+        let mut row = [example.name.clone(), String::new(), String::new()];
+
+        for part in [1, 2] {
+            let record = records.iter().find(|record| record.part == part);
+            let (is_failure, cell) = format_cell(record, example.expected(part));
+            if is_failure {
+                failures += 1;
+            }
+            row[usize::from(part)] = cell;
+        }
+
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..3)
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for (i, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        let line = cells.join(" │ ");
+        if i == 0 {
+            println!("{ANSI_BOLD}{}{ANSI_RESET}", line.trim_end());
+        } else {
+            println!("{}", line.trim_end());
+        }
+    }
+
+    println!();
+
+    if failures > 0 {
+        println!("{ANSI_BOLD}{failures} part(s) do not match their expected answer.{ANSI_RESET}");
+        false
+    } else {
+        println!("All expected answers match.");
+        true
+    }
+}
+
+/// Formats the result of a part as a cell of the grid and returns whether it does not match the expected answer.
+/// Parts without an expected answer are shown, but are not checked.
+fn format_cell(record: Option<&Record>, expected: Option<&str>) -> (bool, String) {
+    let answer = record
+        .filter(|record| record.status == Status::Solved)
+        .and_then(|record| record.answer.as_deref());
+
+    let got = match (record, answer) {
+        (_, Some(answer)) => answer.to_string(),
+        (Some(record), None) if record.status == Status::TimedOut => "timed out".into(),
+        (Some(record), None) if record.status.is_failure() => {
+            record.error.clone().unwrap_or_else(|| "failed".into())
+        }
+        _ => "no answer".into(),
+    };
+
+    match (expected, answer) {
+        (Some(expected), Some(answer)) if expected == answer => (false, format!("✔ {answer}")),
+        (Some(expected), _) => (true, format!("✖ expected {expected}, got {got}")),
+        (None, _) => (false, format!("- {got}")),
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{example_options, format_cell, parse, Example};
+    use crate::day;
+    use crate::template::record::{Record, Status};
+    use crate::template::runner::RunOptions;
+    use crate::template::stats::Stats;
+    use std::io;
+
+    #[test]
+    fn parses_manifests() {
+        let content = "[a]\nfile = \"examples/01.txt\"\npart_1 = 142\n\n[b]\ninput = \"\"\"\ntwo1nine\n\"\"\"\npart_2 = \"29\"\n";
+        let examples = parse(content, |path| Ok(format!("contents of {path}"))).unwrap();
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    name: "a".into(),
+                    input: "contents of examples/01.txt".into(),
+                    part_1: Some("142".into()),
+                    part_2: None,
+                },
+                Example {
+                    name: "b".into(),
+                    input: "two1nine\n".into(),
+                    part_1: None,
+                    part_2: Some("29".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        let read_file = |_: &str| Err(io::Error::from(io::ErrorKind::NotFound));
+        assert!(parse("part_1 = 1", read_file).is_err());
+        assert!(parse("[a]\npart_1 = 1", read_file).is_err());
+        assert!(parse("[a]\ninput = \"\"\npart_3 = 1", read_file).is_err());
+        assert!(parse("[a]\nfile = \"missing.txt\"", read_file).is_err());
+    }
+
+    #[test]
+    fn never_submits_examples() {
+        let options = RunOptions {
+            submit: Some(1),
+            examples: true,
+            ..RunOptions::default()
+        };
+        let options = example_options(&options);
+        assert_eq!(options.submit, None);
+        assert!(options.quiet);
+    }
+
+    #[test]
+    fn formats_cells() {
+        let record = Record {
+            day: day!(1),
+            part: 1,
+            answer: Some("142".into()),
+            stats: Stats::default(),
+            memory: None,
+            error: None,
+            status: Status::Solved,
+        };
+        assert_eq!(
+            format_cell(Some(&record), Some("142")),
+            (false, "✔ 142".into())
+        );
+        assert_eq!(
+            format_cell(Some(&record), Some("143")),
+            (true, "✖ expected 143, got 142".into())
+        );
+        assert_eq!(format_cell(Some(&record), None), (false, "- 142".into()));
+        assert_eq!(
+            format_cell(None, Some("143")),
+            (true, "✖ expected 143, got no answer".into())
+        );
+    }
+}
@@ -0,0 +1,164 @@
+/// Selects the input a solution runs against: the puzzle input, an example, any file or stdin.
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::{fs, io};
+
+use crate::template::{get_data_dir, try_read_file};
+use crate::{Day, Year};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/YYYY/inputs`.
+    #[default]
+    Puzzle,
+    /// An example in `data/YYYY/examples`, or in `data/YYYY/examples_<name>` if a name is given.
+    Example(Option<String>),
+    /// Any file, e.g. a colleague's input or a generated stress input.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Removes `--input <path>`, `--stdin` and `--example [name]` from command-line arguments and returns the
+    /// selected source. The name of an example is optional, so it must not start with `-`.
+    pub fn take_from_args(args: &mut Vec<OsString>) -> Result<Self, String> {
+        let mut source = None;
+        let mut remaining = vec![];
+        let mut iter = std::mem::take(args).into_iter().peekable();
+
+        while let Some(arg) = iter.next() {
+            let selected = match arg.to_str() {
+                Some("--input") => match iter.next() {
+                    Some(path) => InputSource::File(path.into()),
+                    None => return Err("--input requires a path".into()),
+                },
+                Some("--stdin") => InputSource::Stdin,
+                Some("--example") => {
+                    let has_name = iter
+                        .peek()
+                        .and_then(|next| next.to_str())
+                        .is_some_and(|next| !next.starts_with('-'));
+                    let name = has_name
+                        .then(|| iter.next())
+                        .flatten()
+                        .map(|name| name.to_string_lossy().into_owned());
+                    InputSource::Example(name)
+                }
+                _ => {
+                    remaining.push(arg);
+                    continue;
+                }
+            };
+
+            if source.replace(selected).is_some() {
+                return Err("only one of --input, --stdin and --example can be used".into());
+            }
+        }
+
+        *args = remaining;
+        Ok(source.unwrap_or_default())
+    }
+
+    /// The command-line arguments that select this source, e.g. to pass it on to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(name)) => vec!["--example".into(), name.clone()],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--stdin".into()],
+        }
+    }
+
+    pub fn read(&self, year: Year, day: Day) -> io::Result<String> {
+        match self {
+            InputSource::Puzzle => try_read_file("inputs", year, day),
+            InputSource::Example(name) => {
+                try_read_file(&example_folder(name.as_deref()), year, day)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => io::read_to_string(io::stdin()),
+        }
+    }
+
+    /// Describes the source for error messages, e.g. `data/2023/examples_b/01.txt`.
+    #[must_use]
+    pub fn describe(&self, year: Year, day: Day) -> String {
+        match self {
+            InputSource::Puzzle => get_data_dir(year)
+                .join("inputs")
+                .join(format!("{day}.txt"))
+                .display()
+                .to_string(),
+            InputSource::Example(name) => get_data_dir(year)
+                .join(example_folder(name.as_deref()))
+                .join(format!("{day}.txt"))
+                .display()
+                .to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "stdin".into(),
+        }
+    }
+}
+
+fn example_folder(name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("examples_{name}"),
+        None => "examples".into(),
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::InputSource;
+    use std::ffi::OsString;
+
+    fn take(args: &[&str]) -> (Result<InputSource, String>, Vec<OsString>) {
+        let mut args = args.iter().map(OsString::from).collect();
+        let source = InputSource::take_from_args(&mut args);
+        (source, args)
+    }
+
+    #[test]
+    fn takes_sources_from_args() {
+        let (source, rest) = take(&["solve", "5", "--time"]);
+        assert_eq!(source, Ok(InputSource::Puzzle));
+        assert_eq!(rest, ["solve", "5", "--time"]);
+
+        let (source, rest) = take(&["solve", "5", "--input", "stress.txt", "--time"]);
+        assert_eq!(source, Ok(InputSource::File("stress.txt".into())));
+        assert_eq!(rest, ["solve", "5", "--time"]);
+
+        let (source, rest) = take(&["solve", "5", "--example", "--time"]);
+        assert_eq!(source, Ok(InputSource::Example(None)));
+        assert_eq!(rest, ["solve", "5", "--time"]);
+
+        let (source, _) = take(&["solve", "5", "--example", "b"]);
+        assert_eq!(source, Ok(InputSource::Example(Some("b".into()))));
+
+        let (source, _) = take(&["solve", "5", "--stdin"]);
+        assert_eq!(source, Ok(InputSource::Stdin));
+    }
+
+    #[test]
+    fn rejects_invalid_sources() {
+        assert!(take(&["solve", "5", "--input"]).0.is_err());
+        assert!(take(&["solve", "5", "--stdin", "--example"]).0.is_err());
+    }
+
+    #[test]
+    fn roundtrips_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example(None),
+            InputSource::Example(Some("b".into())),
+            InputSource::File("stress.txt".into()),
+            InputSource::Stdin,
+        ] {
+            let args = source.to_args();
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            assert_eq!(take(&args).0, Ok(source));
+        }
+    }
+}
@@ -0,0 +1,312 @@
+/// Minimal JSON support for the machine-readable output of the template.
+/// Only what the runner needs is implemented: flat values, arrays and objects, no streaming.
+use std::fmt::{self, Display, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// Numbers are kept in their textual representation to avoid losing precision on large integers.
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid json: {}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Value {
+    pub fn number(n: impl Display) -> Self {
+        Value::Number(n.to_string())
+    }
+
+    pub fn string(s: impl Into<String>) -> Self {
+        Value::String(s.into())
+    }
+
+    /// Looks up a key of an object, returns [`None`] for missing keys and non-objects.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            Value::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    pub fn parse(s: &str) -> Result<Value, Error> {
+        let mut parser = Parser {
+            chars: s.char_indices().peekable(),
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.chars.next() {
+            None => Ok(value),
+            Some((i, _)) => Err(Error(format!("trailing characters at {i}"))),
+        }
+    }
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Number(n) => f.write_str(n),
+            Value::String(s) => write_escaped(f, s),
+            Value::Array(values) => {
+                f.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            Value::Object(entries) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((i, c)) => Err(Error(format!("expected `{expected}` at {i}, found `{c}`"))),
+            None => Err(Error(format!("expected `{expected}`, found end of input"))),
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: Value) -> Result<Value, Error> {
+        for c in literal.chars() {
+            self.expect(c)?;
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some((_, 'n')) => self.literal("null", Value::Null),
+            Some((_, 't')) => self.literal("true", Value::Bool(true)),
+            Some((_, 'f')) => self.literal("false", Value::Bool(false)),
+            Some((_, '"')) => self.string().map(Value::String),
+            Some((_, '[')) => self.array(),
+            Some((_, '{')) => self.object(),
+            Some((_, c)) if *c == '-' || c.is_ascii_digit() => Ok(self.number()),
+            Some((i, c)) => Err(Error(format!("unexpected `{c}` at {i}"))),
+            None => Err(Error("unexpected end of input".into())),
+        }
+    }
+
+    fn number(&mut self) -> Value {
+        let mut n = String::new();
+        while let Some((_, c)) = self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            n.push(c);
+        }
+        Value::Number(n)
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(s),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, '"')) => s.push('"'),
+                    Some((_, '\\')) => s.push('\\'),
+                    Some((_, '/')) => s.push('/'),
+                    Some((_, 'b')) => s.push('\u{8}'),
+                    Some((_, 'f')) => s.push('\u{c}'),
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 'r')) => s.push('\r'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((i, 'u')) => {
+                        let hex: String = (0..4)
+                            .filter_map(|_| self.chars.next())
+                            .map(|x| x.1)
+                            .collect();
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| Error(format!("invalid unicode escape at {i}")))?;
+                        s.push(c);
+                    }
+                    Some((i, c)) => return Err(Error(format!("invalid escape `\\{c}` at {i}"))),
+                    None => return Err(Error("unterminated string".into())),
+                },
+                Some((_, c)) => s.push(c),
+                None => return Err(Error("unterminated string".into())),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, Error> {
+        self.expect('[')?;
+        let mut values = vec![];
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == ']').is_some() {
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => return Ok(Value::Array(values)),
+                Some((i, c)) => return Err(Error(format!("unexpected `{c}` at {i}"))),
+                None => return Err(Error("unterminated array".into())),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Error> {
+        self.expect('{')?;
+        let mut entries = vec![];
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == '}').is_some() {
+            return Ok(Value::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, '}')) => return Ok(Value::Object(entries)),
+                Some((i, c)) => return Err(Error(format!("unexpected `{c}` at {i}"))),
+                None => return Err(Error("unterminated object".into())),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn roundtrips_objects() {
+        let value = Value::Object(vec![
+            ("day".into(), Value::number(1)),
+            ("answer".into(), Value::string("a \"quoted\"\nline @ (1)")),
+            ("missing".into(), Value::Null),
+            (
+                "list".into(),
+                Value::Array(vec![Value::Bool(true), Value::number(1.5)]),
+            ),
+        ]);
+        let serialized = value.to_string();
+        assert!(!serialized.contains('\n'));
+        assert_eq!(Value::parse(&serialized).unwrap(), value);
+    }
+
+    #[test]
+    fn parses_whitespace_and_escapes() {
+        let value = Value::parse(r#" { "a" : [ 1 , -2e3 ], "b": "é\t" } "#).unwrap();
+        assert_eq!(value.get("a").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(value.get("b").unwrap().as_str(), Some("é\t"));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(Value::parse("{\"a\": 1").is_err());
+        assert!(Value::parse("Part 1: 42 (1.0ms)").is_err());
+        assert!(Value::parse("{} x").is_err());
+    }
+}
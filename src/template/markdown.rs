@@ -0,0 +1,360 @@
+/// Converts the HTML of a puzzle page into the Markdown that is stored in `data/YYYY/puzzles/NN.md`.
+///
+/// Only the markup that puzzle descriptions use is supported: headings, paragraphs, lists, code blocks, inline code,
+/// emphasis and links. Other tags are dropped and only their text is kept.
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// The `<article>` elements of a page, i.e. the puzzle parts or the response to an answer.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + len]);
+        rest = &rest[content_start + len..];
+    }
+
+    articles
+}
+
+/// Decodes the named entities that puzzle pages use and numeric character references.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Converts the puzzle parts of a page, separated by a blank line.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let parts: Vec<String> = articles(html).into_iter().map(to_markdown).collect();
+    parts.join("\n\n") + "\n"
+}
+
+/// A tag of an HTML fragment, e.g. `<a href="/2023">` or `</a>`.
+struct Tag<'a> {
+    name: String,
+    is_closing: bool,
+    source: &'a str,
+}
+
+impl Tag<'_> {
+    fn parse(source: &str) -> Tag<'_> {
+        let inner = source.trim_start_matches('<').trim_end_matches('>').trim();
+        let (is_closing, inner) = match inner.strip_prefix('/') {
+            Some(inner) => (true, inner),
+            None => (false, inner),
+        };
+        let name = inner
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        Tag {
+            name,
+            is_closing,
+            source,
+        }
+    }
+
+    /// The value of an attribute, e.g. the `href` of a link.
+    fn attribute(&self, name: &str) -> Option<String> {
+        let start = self.source.find(&format!("{name}=\""))? + name.len() + 2;
+        let len = self.source[start..].find('"')?;
+        Some(decode_entities(&self.source[start..start + len]))
+    }
+}
+
+/// An inline code span that is being collected. Puzzles highlight answers as `<code><em>42</em></code>`, such spans
+/// are emphasized as a whole.
+struct Code {
+    text: String,
+    is_emphasized: bool,
+}
+
+#[derive(Default)]
+struct Writer {
+    blocks: Vec<String>,
+    inline: String,
+    /// The lines of the current list.
+    list: Vec<String>,
+    /// The content of the current `<pre>` block, if any.
+    pre: Option<String>,
+    code: Option<Code>,
+    /// The position in [`Writer::inline`] where each open link starts, and its target.
+    links: Vec<(usize, Option<String>)>,
+}
+
+impl Writer {
+    fn text(&mut self, text: &str) {
+        let text = decode_entities(text);
+
+        if let Some(pre) = &mut self.pre {
+            pre.push_str(&text);
+            return;
+        }
+
+        // outside of code blocks, any whitespace is a single space.
+        let mut collapsed = String::new();
+        for (i, word) in text.split_whitespace().enumerate() {
+            if i > 0 {
+                collapsed.push(' ');
+            }
+            collapsed.push_str(word);
+        }
+        if text.starts_with(char::is_whitespace) {
+            collapsed.insert(0, ' ');
+        }
+        if text.ends_with(char::is_whitespace) && !collapsed.ends_with(' ') {
+            collapsed.push(' ');
+        }
+
+        match &mut self.code {
+            Some(code) => code.text.push_str(&collapsed),
+            None => {
+                let collapsed = escape(&collapsed);
+                // no leading space at the start of a block, and no double spaces between elements.
+                let collapsed = if self.inline.is_empty() || self.inline.ends_with(' ') {
+                    collapsed.trim_start()
+                } else {
+                    &collapsed
+                };
+                self.inline.push_str(collapsed);
+            }
+        }
+    }
+
+    fn tag(&mut self, tag: &Tag) {
+        if self.pre.is_some() && !(tag.name == "pre" && tag.is_closing) {
+            // code blocks keep their text only, e.g. without the highlighting of their answers.
+            return;
+        }
+
+        match (tag.name.as_str(), tag.is_closing) {
+            ("h1" | "h2" | "h3", true) => {
+                let heading = self.take_inline();
+                if !heading.is_empty() {
+                    self.blocks.push(format!("## {heading}"));
+                }
+            }
+            ("p", true) => self.flush(),
+            ("p" | "h1" | "h2" | "h3" | "ul" | "ol", false) => self.flush(),
+            ("li", false) => {
+                let item = self.take_inline();
+                if !item.is_empty() {
+                    self.list.push(format!("- {item}"));
+                }
+            }
+            ("li", true) => {
+                let item = self.take_inline();
+                self.list.push(format!("- {item}"));
+            }
+            ("ul" | "ol", true) => self.flush(),
+            ("pre", false) => {
+                self.flush();
+                self.pre = Some(String::new());
+            }
+            ("pre", true) => {
+                let pre = self.pre.take().unwrap_or_default();
+                let pre = pre.strip_suffix('\n').unwrap_or(&pre);
+                self.blocks.push(format!("```\n{pre}\n```"));
+            }
+            ("code", false) => {
+                self.code = Some(Code {
+                    text: String::new(),
+                    is_emphasized: false,
+                });
+            }
+            ("code", true) => {
+                if let Some(code) = self.code.take() {
+                    let span = code_span(&code.text);
+                    if code.is_emphasized {
+                        self.inline.push_str(&format!("*{span}*"));
+                    } else {
+                        self.inline.push_str(&span);
+                    }
+                }
+            }
+            ("em", _) => match &mut self.code {
+                Some(code) => code.is_emphasized |= code.text.is_empty() && !tag.is_closing,
+                None => self.inline.push('*'),
+            },
+            ("a", false) => {
+                let href = tag
+                    .attribute("href")
+                    .map(|href| match href.strip_prefix('/') {
+                        Some(path) => format!("{BASE_URL}/{path}"),
+                        None => href,
+                    });
+                self.links.push((self.inline.len(), href));
+            }
+            ("a", true) => {
+                if let Some((start, Some(href))) = self.links.pop() {
+                    self.inline.insert(start, '[');
+                    self.inline.push_str(&format!("]({href})"));
+                }
+            }
+            ("br", _) => self.inline.push_str("  \n"),
+            _ => {}
+        }
+    }
+
+    fn take_inline(&mut self) -> String {
+        let inline = std::mem::take(&mut self.inline);
+        inline.trim().to_string()
+    }
+
+    /// Ends the current paragraph or list.
+    fn flush(&mut self) {
+        let inline = self.take_inline();
+        if !inline.is_empty() {
+            self.blocks.push(inline);
+        }
+        if !self.list.is_empty() {
+            let list = std::mem::take(&mut self.list);
+            self.blocks.push(list.join("\n"));
+        }
+    }
+}
+
+/// Wraps inline code in enough backticks for the code to contain backticks itself.
+fn code_span(code: &str) -> String {
+    let longest_run = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+
+    if longest_run > 0 {
+        format!("{fence} {code} {fence}")
+    } else {
+        format!("{fence}{code}{fence}")
+    }
+}
+
+/// Escapes the characters that would otherwise start emphasis or inline code.
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '`' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Converts an HTML fragment, e.g. one puzzle part.
+pub fn to_markdown(html: &str) -> String {
+    let mut writer = Writer::default();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        writer.text(&rest[..start]);
+
+        // comments may contain `>`.
+        if rest[start..].starts_with("<!--") {
+            let end = rest[start..]
+                .find("-->")
+                .map_or(rest.len(), |end| start + end + 3);
+            rest = &rest[end..];
+            continue;
+        }
+
+        let Some(len) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        writer.tag(&Tag::parse(&rest[start..=start + len]));
+        rest = &rest[start + len + 1..];
+    }
+
+    writer.text(rest);
+    writer.flush();
+    writer.blocks.join("\n\n")
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{decode_entities, puzzle_to_markdown, to_markdown};
+
+    #[test]
+    fn converts_part_one() {
+        let html = include_str!("fixtures/puzzle_part_one.html");
+        let expected = include_str!("fixtures/puzzle_part_one.md");
+        assert_eq!(puzzle_to_markdown(html), expected);
+    }
+
+    #[test]
+    fn converts_both_parts() {
+        let html = include_str!("fixtures/puzzle_both_parts.html");
+        let expected = include_str!("fixtures/puzzle_both_parts.md");
+        assert_eq!(puzzle_to_markdown(html), expected);
+    }
+
+    #[test]
+    fn converts_inline_markup() {
+        assert_eq!(
+            to_markdown("<p>The answer is <code><em>142</em></code>, not <code>a`b</code>.</p>"),
+            "The answer is *`142`*, not `` a`b ``."
+        );
+        assert_eq!(
+            to_markdown("<p>Read <a href=\"/2023/about\">the <em>about</em> page</a> *now*.</p>"),
+            "Read [the *about* page](https://adventofcode.com/2023/about) \\*now\\*."
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("&lt;a&gt; &amp;&amp; &quot;b&quot; &#39;c&#x27; & d"),
+            "<a> && \"b\" 'c' & d"
+        );
+    }
+}
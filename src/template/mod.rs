@@ -1,36 +1,157 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Day, Year};
+use std::path::PathBuf;
+use std::{env, fs, io, str::FromStr};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod examples;
+pub mod input;
+pub mod json;
+pub mod markdown;
 pub mod readme_benchmarks;
+pub mod readme_stars;
+pub mod record;
+pub mod registry;
 pub mod runner;
+pub mod solution;
+pub mod stats;
+pub mod submissions;
+pub mod toml;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Output format of `solve` and `all`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output with ANSI decorations.
+    #[default]
+    Text,
+    /// One JSON [`record::Record`] per line and part.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            s => Err(format!(
+                "unknown output format \"{s}\", expecting \"text\" or \"json\""
+            )),
+        }
+    }
+}
+
+/// Helper function that reads a text file of a year, e.g. `data/2023/examples/01.txt`, to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    let f = try_read_file(folder, year, day);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Same as [`read_file`], but returns an error instead of panicking if the file does not exist.
+pub fn try_read_file(folder: &str, year: Year, day: Day) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd
+        .join(get_data_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
+    fs::read_to_string(filepath)
+}
+
+/// The folder with the inputs, examples and other data of a year, e.g. `data/2023`.
+#[must_use]
+pub fn get_data_dir(year: Year) -> PathBuf {
+    PathBuf::from("data").join(year.to_string())
+}
+
+/// The name of the binary of a solution, e.g. `2023-01` for `src/bin/2023-01.rs`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
+/// Creates the constants `YEAR` and `DAY`, implements [`solution::Solution`] for the struct `Puzzle` on top of the free functions
+/// `part_one` and `part_two` and registers it as `SOLUTION`.
+///
+/// Without a parse function, both parts receive the raw input. Parts return an `Option` or a `Result` of an answer
+/// that implements [`std::fmt::Display`], see [`solution::PartOutput`].
+/// If a parse function and its return type are passed as third argument, it is run (and timed) once and both parts
+/// receive a reference to its output. The type may borrow from the input through the lifetime `'a`:
+///
+/// ```ignore
+/// advent_of_code::solution!(2023, 8, parse -> Map<'a>);
+///
+/// fn parse(input: &str) -> Map<'_> { ... }
+/// pub fn part_one(map: &Map) -> Option<u32> { ... }
+/// ```
+///
+/// Solutions that want typed answers can implement [`solution::Solution`] themselves instead.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@impl $year:expr, $day:expr, $parse:expr, $input:ty, $has_parse_step:expr) => {
+        /// The year of the event.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
+
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        /// The solution of the current day.
+        pub struct Puzzle;
+
+        impl advent_of_code::template::solution::Solution for Puzzle {
+            const YEAR: advent_of_code::Year = YEAR;
+            const DAY: advent_of_code::Day = DAY;
+            const HAS_PARSE_STEP: bool = $has_parse_step;
+            type Input<'a> = $input;
+            type Answer1 = advent_of_code::template::solution::FormattedOutput;
+            type Answer2 = advent_of_code::template::solution::FormattedOutput;
+
+            fn parse(input: &str) -> Self::Input<'_> {
+                ($parse)(input)
+            }
+
+            fn part_one(input: &Self::Input<'_>) -> Self::Answer1 {
+                advent_of_code::template::solution::FormattedOutput::new(part_one(input))
+            }
+
+            fn part_two(input: &Self::Input<'_>) -> Self::Answer2 {
+                advent_of_code::template::solution::FormattedOutput::new(part_two(input))
+            }
+        }
+
+        /// One test per example in `data/YYYY/examples/NN.toml`, generated by `build.rs`.
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/examples/",
+                stringify!($year),
+                "/",
+                stringify!($day),
+                ".rs"
+            ));
+        }
+
+        /// Entry of this solution in the registry of the main binary.
+        pub const SOLUTION: advent_of_code::template::registry::Entry =
+            advent_of_code::template::registry::Entry::new::<Puzzle>();
+
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            advent_of_code::template::registry::run_main(&SOLUTION);
         }
     };
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, |input| input, &'a str, false);
+    };
+    ($year:expr, $day:expr, $parse:ident -> $input:ty) => {
+        $crate::solution!(@impl $year, $day, $parse, $input, true);
+    };
 }
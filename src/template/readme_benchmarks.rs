@@ -1,10 +1,21 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+///
+/// A README can hold several tables, each between two identical markers that start with `<!--- benchmarking table`.
+/// The marker names the table and sets its optional columns, see [`TableOptions`].
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::alloc::{format_bytes, MemoryUsage};
+use crate::template::record::Status;
+use crate::template::stats::Stats;
+use crate::template::{get_bin_name, get_data_dir};
+use crate::{Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+static MARKER_PREFIX: &str = "<!--- benchmarking table";
+static MARKER_SUFFIX: &str = "--->";
 
 #[derive(Debug)]
 pub enum Error {
@@ -18,31 +29,76 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PartTiming {
+    pub stats: Stats,
+    /// Only present if the solution was run with `--mem`.
+    pub memory: Option<MemoryUsage>,
+    /// Parts that did not finish are shown in the table, but their stats are meaningless.
+    pub status: Status,
+}
+
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Only present for solutions with a shared parse step.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+impl Timings {
+    /// The parse step (if any) and both parts along with their names.
+    pub fn steps(&self) -> [(&'static str, Option<PartTiming>); 3] {
+        [
+            ("Parse", self.parse),
+            ("Part 1", self.part_1),
+            ("Part 2", self.part_2),
+        ]
+    }
+}
+
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(year, day))
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Where an export of the benchmarks of a year is written, e.g. `data/2023/benchmarks.csv`.
+#[must_use]
+pub fn get_path_for_export(year: Year, format: ExportFormat) -> String {
+    let extension = match format {
+        ExportFormat::Csv => "csv",
+        ExportFormat::Html => "html",
+    };
+    get_data_dir(year)
+        .join(format!("benchmarks.{extension}"))
+        .display()
+        .to_string()
+}
+
+/// Locates the table between the two occurrences of a marker.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
@@ -58,73 +114,527 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// All distinct benchmark table markers of a README in order, e.g. `<!--- benchmarking table --->` and
+/// `<!--- benchmarking table overview columns=total --->`.
+fn find_markers(readme: &str) -> Vec<&str> {
+    let mut markers: Vec<&str> = vec![];
+
+    for (start, _) in readme.match_indices(MARKER_PREFIX) {
+        let Some(len) = readme[start..].find(MARKER_SUFFIX) else {
+            continue;
+        };
+        let marker = &readme[start..start + len + MARKER_SUFFIX.len()];
+        if !markers.contains(&marker) {
+            markers.push(marker);
+        }
+    }
+
+    markers
+}
+
+/// The options of one benchmark table, given in its marker: an optional name, followed by `columns=` with any of
+/// `total`, `percent` and `change`, and `slowest=` with the number of slowest days to highlight.
+///
+/// E.g. `<!--- benchmarking table overview columns=total,percent slowest=3 --->`.
+#[derive(Debug, Default, PartialEq)]
+struct TableOptions {
+    name: Option<String>,
+    total: bool,
+    percent: bool,
+    change: bool,
+    slowest: usize,
+}
+
+impl TableOptions {
+    fn parse(marker: &str) -> Result<Self, Error> {
+        let inner = marker
+            .trim_start_matches(MARKER_PREFIX)
+            .trim_end_matches(MARKER_SUFFIX);
+        // the first word without a value is the name, it tells tables and their headings apart.
+        let mut options = Self {
+            name: inner
+                .split_whitespace()
+                .next()
+                .filter(|word| !word.contains('='))
+                .map(String::from),
+            ..Self::default()
+        };
+
+        for option in inner.split_whitespace().filter(|word| word.contains('=')) {
+            match option.split_once('=') {
+                Some(("columns", columns)) => {
+                    for column in columns.split(',') {
+                        match column {
+                            "total" => options.total = true,
+                            "percent" => options.percent = true,
+                            "change" => options.change = true,
+                            _ => {
+                                return Err(Error::Parser(format!(
+                                    "{marker}: unknown column \"{column}\"."
+                                )))
+                            }
+                        }
+                    }
+                }
+                Some(("slowest", count)) => {
+                    options.slowest = count.parse().map_err(|_| {
+                        Error::Parser(format!("{marker}: invalid number of slowest days."))
+                    })?;
+                }
+                _ => {
+                    return Err(Error::Parser(format!(
+                        "{marker}: unknown option \"{option}\"."
+                    )))
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Formats the statistics of a part as a table cell.
+/// Benchmarked parts show the median and standard deviation, followed by the remaining statistics in a smaller font.
+fn format_cell(timing: Option<PartTiming>) -> String {
+    match timing.map(|t| (t.status, t.stats)) {
+        None | Some((Status::Unsolved, _)) => "-".into(),
+        Some((Status::TimedOut, stats)) => format!("⏱ timed out after {:?}", stats.median),
+        Some((Status::Failed, _)) => "✖ failed".into(),
+        Some((Status::Errored, _)) => "✖ error".into(),
+        Some((Status::Solved, stats)) if stats.samples == 1 => format!("`{:.1?}`", stats.median),
+        Some((Status::Solved, stats)) => format!(
+            "`{:.1?}` ± {:.1?}<br><sub>min {:.1?} · max {:.1?} · p95 {:.1?} · {} outliers</sub>",
+            stats.median, stats.stddev, stats.min, stats.max, stats.p95, stats.outliers
+        ),
+    }
+}
+
+/// Formats the memory usage of a part as a table cell: peak memory, followed by the total allocations in a smaller font.
+fn format_memory_cell(timing: Option<PartTiming>) -> String {
+    match timing.and_then(|t| t.memory) {
+        None => "-".into(),
+        Some(memory) => format!(
+            "`{}`<br><sub>{} in {} allocs</sub>",
+            format_bytes(memory.peak_bytes),
+            format_bytes(memory.allocated_bytes),
+            memory.allocations
+        ),
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+fn sum_nanos(timings: &[Timings]) -> f64 {
+    timings.iter().map(|t| t.total_nanos).sum()
+}
+
+/// The share of a day in the runtime of all days, in percent.
+fn percent_of(timing: &Timings, total_nanos: f64) -> f64 {
+    if total_nanos == 0.0 {
+        return 0.0;
+    }
+    timing.total_nanos / total_nanos * 100.0
+}
+
+/// The relative change of a day's total runtime since the previous run in percent, if both runs solved the day.
+fn change_since(timing: &Timings, previous: Option<&[Timings]>) -> Option<f64> {
+    let before = previous?.iter().find(|t| t.day == timing.day)?.total_nanos;
+    if before == 0.0 || timing.total_nanos == 0.0 {
+        return None;
+    }
+    Some((timing.total_nanos - before) / before * 100.0)
+}
+
+/// The `count` days with the highest total runtime, slowest first.
+fn slowest_days(timings: &[Timings], count: usize) -> Vec<&Timings> {
+    let mut slowest: Vec<&Timings> = timings.iter().filter(|t| t.total_nanos > 0.0).collect();
+    slowest.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+    slowest.truncate(count);
+    slowest
+}
+
+fn construct_table(
+    marker: &str,
+    options: &TableOptions,
+    year: Year,
+    timings: &[Timings],
+    previous: Option<&[Timings]>,
+    total_millis: f64,
+) -> String {
+    let header = match &options.name {
+        Some(name) => format!("## Benchmarks ({name})"),
+        None => "## Benchmarks".to_string(),
+    };
+    let total_nanos = sum_nanos(timings);
+
+    let with_parse = timings.iter().any(|timing| timing.parse.is_some());
+    let with_memory = timings
+        .iter()
+        .flat_map(Timings::steps)
+        .any(|(_, timing)| timing.is_some_and(|t| t.memory.is_some()));
+
+    // skip the parse column if no solution has a parse step.
+    let steps = |timing: &Timings| {
+        timing
+            .steps()
+            .into_iter()
+            .filter(|(name, _)| with_parse || *name != "Parse")
+            .collect::<Vec<_>>()
+    };
+
+    let step_names: &[&str] = if with_parse {
+        &["Parse", "Part 1", "Part 2"]
+    } else {
+        &["Part 1", "Part 2"]
+    };
+
+    let mut columns = vec!["Day".to_string()];
+    columns.extend(step_names.iter().map(ToString::to_string));
+    if options.total {
+        columns.push("Total".into());
+    }
+    if options.percent {
+        columns.push("% of total".into());
+    }
+    if options.change {
+        columns.push("Change".into());
+    }
+    if with_memory {
+        columns.extend(step_names.iter().map(|name| format!("{name} memory")));
+    }
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", columns.join(" | ")),
+        format!("|{}", " :---: |".repeat(columns.len())),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        let path = get_path_for_bin(year, timing.day);
+        let mut cells = vec![format!("[Day {}]({})", timing.day.into_inner(), path)];
+        cells.extend(steps(timing).into_iter().map(|(_, t)| format_cell(t)));
+        if options.total {
+            cells.push(format!("`{}`", format_nanos(timing.total_nanos)));
+        }
+        if options.percent {
+            cells.push(format!("{:.1}%", percent_of(timing, total_nanos)));
+        }
+        if options.change {
+            cells.push(change_since(timing, previous).map_or("-".into(), |c| format!("{c:+.1}%")));
+        }
+        if with_memory {
+            cells.extend(
+                steps(timing)
+                    .into_iter()
+                    .map(|(_, t)| format_memory_cell(t)),
+            );
+        }
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+
+    let previous_total =
+        previous.map(|previous| previous.iter().map(|t| t.total_nanos).sum::<f64>());
+    match previous_total {
+        Some(before) if options.change && before > 0.0 => lines.push(format!(
+            "**Total: {total_millis:.2}ms** ({:+.1}% since the last run)",
+            (total_nanos - before) / before * 100.0
+        )),
+        _ => lines.push(format!("**Total: {total_millis:.2}ms**")),
+    }
+
+    let slowest = slowest_days(timings, options.slowest);
+    if !slowest.is_empty() {
+        let days: Vec<String> = slowest
+            .iter()
+            .map(|timing| {
+                format!(
+                    "[Day {}]({}) (`{}`, {:.1}%)",
+                    timing.day.into_inner(),
+                    get_path_for_bin(year, timing.day),
+                    format_nanos(timing.total_nanos),
+                    percent_of(timing, total_nanos)
+                )
+            })
+            .collect();
+        lines.push(String::new());
+        lines.push(format!("Slowest: {}", days.join(" · ")));
+    }
+
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Updates all benchmark tables of a README. `previous` are the timings of the last recorded run, if any.
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: &[Timings],
+    previous: Option<&[Timings]>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let markers: Vec<String> = find_markers(s).into_iter().map(String::from).collect();
+    if markers.is_empty() {
+        return Err(Error::Parser("Could not find table start position.".into()));
+    }
+
+    for marker in markers {
+        let options = TableOptions::parse(&marker)?;
+        let positions = locate_table(s, &marker)?;
+        let table = construct_table(&marker, &options, year, timings, previous, total_millis);
+        s.replace_range(positions.pos_start..positions.pos_end, &table);
+    }
+
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(
+    year: Year,
+    timings: &[Timings],
+    previous: Option<&[Timings]>,
+    total_millis: f64,
+) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, previous, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+/// A file format that the benchmarks can be exported to, in addition to the README.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Html,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Self::Csv),
+            "html" => Ok(Self::Html),
+            _ => Err(format!(
+                "unknown export format \"{s}\", expected csv or html"
+            )),
+        }
+    }
+}
+
+/// The median of a solved part in nanoseconds, or nothing.
+fn median_nanos(timing: Option<PartTiming>) -> String {
+    match timing {
+        Some(timing) if timing.status == Status::Solved => {
+            timing.stats.median.as_nanos().to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+/// One line per day with the medians of its steps in nanoseconds, its total, its share of all days and the change
+/// since the previous run in percent. Cells of unsolved or failed parts are empty.
+fn to_csv(timings: &[Timings], previous: Option<&[Timings]>) -> String {
+    let total_nanos = sum_nanos(timings);
+    let mut lines =
+        vec!["day,parse_ns,part_1_ns,part_2_ns,total_ns,percent,change_percent".to_string()];
+
+    for timing in timings {
+        lines.push(format!(
+            "{},{},{},{},{:.0},{:.2},{}",
+            timing.day.into_inner(),
+            median_nanos(timing.parse),
+            median_nanos(timing.part_1),
+            median_nanos(timing.part_2),
+            timing.total_nanos,
+            percent_of(timing, total_nanos),
+            change_since(timing, previous).map_or(String::new(), |c| format!("{c:.2}"))
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// The days that are highlighted in the HTML export if no table of the README sets `slowest=`.
+const HTML_SLOWEST_DAYS: usize = 3;
+
+/// The number of days that the HTML export highlights: the most that a table of the README lists with `slowest=`.
+fn html_slowest_days(readme: &str) -> Result<usize, Error> {
+    let mut slowest = None;
+    for marker in find_markers(readme) {
+        let options = TableOptions::parse(marker)?;
+        if options.slowest > 0 {
+            slowest = Some(slowest.unwrap_or(0).max(options.slowest));
+        }
+    }
+    Ok(slowest.unwrap_or(HTML_SLOWEST_DAYS))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// A standalone HTML page with the table of all columns, the `slowest` days are highlighted.
+fn to_html(
+    year: Year,
+    timings: &[Timings],
+    previous: Option<&[Timings]>,
+    total_millis: f64,
+    slowest: usize,
+) -> String {
+    let total_nanos = sum_nanos(timings);
+    let slowest = slowest_days(timings, slowest);
+
+    let cell = |timing: Option<PartTiming>| match timing.map(|t| (t.status, t.stats)) {
+        Some((Status::Solved, stats)) => format!("{:.1?}", stats.median),
+        timing => escape_html(&format_cell(timing.map(|(status, stats)| PartTiming {
+            stats,
+            memory: None,
+            status,
+        }))),
+    };
+
+    let mut rows = vec![];
+    for timing in timings {
+        let class = if slowest.iter().any(|t| t.day == timing.day) {
+            " class=\"slowest\""
+        } else {
+            ""
+        };
+        let change = change_since(timing, previous).map_or("-".into(), |c| format!("{c:+.1}%"));
+        rows.push(format!(
+            "      <tr{class}><td>Day {}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.1}%</td><td>{change}</td></tr>",
+            timing.day.into_inner(),
+            cell(timing.parse),
+            cell(timing.part_1),
+            cell(timing.part_2),
+            format_nanos(timing.total_nanos),
+            percent_of(timing, total_nanos),
+        ));
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>Advent of Code {year} Benchmarks</title>
+  <style>
+    body {{ font-family: sans-serif; margin: 2rem; }}
+    table {{ border-collapse: collapse; }}
+    th, td {{ padding: 0.25rem 0.75rem; border-bottom: 1px solid #ddd; text-align: right; }}
+    th:first-child, td:first-child {{ text-align: left; }}
+    tr.slowest {{ background: #fde8e8; font-weight: bold; }}
+  </style>
+</head>
+<body>
+  <h1>Advent of Code {year} Benchmarks</h1>
+  <table>
+    <thead>
+      <tr><th>Day</th><th>Parse</th><th>Part 1</th><th>Part 2</th><th>Total</th><th>% of total</th><th>Change</th></tr>
+    </thead>
+    <tbody>
+{}
+    </tbody>
+    <tfoot>
+      <tr><th>Total</th><td></td><td></td><td></td><th>{total_millis:.2}ms</th><td></td><td></td></tr>
+    </tfoot>
+  </table>
+</body>
+</html>
+"#,
+        rows.join("\n")
+    )
+}
+
+/// Writes the benchmarks to `data/<year>/benchmarks.<format>`.
+pub fn export(
+    year: Year,
+    format: ExportFormat,
+    timings: &[Timings],
+    previous: Option<&[Timings]>,
+    total_millis: f64,
+) -> Result<String, Error> {
+    let content = match format {
+        ExportFormat::Csv => to_csv(timings, previous),
+        ExportFormat::Html => {
+            let readme = String::from_utf8_lossy(&fs::read("README.md")?).to_string();
+            to_html(
+                year,
+                timings,
+                previous,
+                total_millis,
+                html_slowest_days(&readme)?,
+            )
+        }
+    };
+    let path = get_path_for_export(year, format);
+    fs::create_dir_all(get_data_dir(year))?;
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{
+        find_markers, html_slowest_days, to_csv, to_html, update_content, PartTiming, TableOptions,
+        Timings, HTML_SLOWEST_DAYS,
+    };
+    use crate::template::alloc::MemoryUsage;
+    use crate::template::record::Status;
+    use crate::template::stats::Stats;
+    use crate::{day, year};
+    use std::time::Duration;
+
+    static MARKER: &str = "<!--- benchmarking table --->";
+
+    fn millis(ms: u64) -> Option<PartTiming> {
+        Some(PartTiming {
+            stats: Stats::single(Duration::from_millis(ms)),
+            memory: None,
+            status: Status::Solved,
+        })
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                parse: None,
+                part_1: millis(10),
+                part_2: millis(20),
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                parse: None,
+                part_1: millis(30),
+                part_2: millis(40),
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                parse: None,
+                part_1: millis(40),
+                part_2: Stats::from_samples(&[
+                    Duration::from_millis(50),
+                    Duration::from_millis(50),
+                    Duration::from_millis(50),
+                ])
+                .map(|stats| PartTiming {
+                    stats,
+                    memory: None,
+                    status: Status::Solved,
+                }),
                 total_nanos: 9e+10,
             },
         ]
@@ -134,36 +644,92 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), &get_mock_timings(), None, 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), &get_mock_timings(), None, 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, year!(2023), &get_mock_timings(), None, 190.0).unwrap();
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), &get_mock_timings(), None, 190.0).unwrap();
+        update_content(&mut s, year!(2023), &get_mock_timings(), None, 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn adds_parse_column() {
+        let mut timings = get_mock_timings();
+        timings[1].parse = millis(5);
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), &timings, None, 190.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | - | `10.0ms` | `20.0ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |"));
+    }
+
+    #[test]
+    fn adds_memory_columns() {
+        let mut timings = get_mock_timings();
+        timings[0].part_1 = timings[0].part_1.map(|timing| PartTiming {
+            memory: Some(MemoryUsage {
+                allocated_bytes: 3072,
+                allocations: 12,
+                peak_bytes: 1536,
+            }),
+            ..timing
+        });
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), &timings, None, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Part 1 memory | Part 2 memory |"));
+        assert!(
+            s.contains("| [Day 1](./src/bin/2023-01.rs) | `10.0ms` | `20.0ms` | `1.5 KiB`<br><sub>3.0 KiB in 12 allocs</sub> | - |")
+        );
+    }
+
+    #[test]
+    fn shows_timed_out_parts() {
+        let mut timings = get_mock_timings();
+        timings[0].part_2 = Some(PartTiming {
+            stats: Stats::single(Duration::from_secs(5)),
+            memory: None,
+            status: Status::TimedOut,
+        });
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), &timings, None, 190.0).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `10.0ms` | ⏱ timed out after 5s |"));
+    }
+
+    #[test]
+    fn shows_failed_parts() {
+        let mut timings = get_mock_timings();
+        timings[1].part_1 = Some(PartTiming {
+            stats: Stats::default(),
+            memory: None,
+            status: Status::Failed,
+        });
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, year!(2023), &timings, None, 190.0).unwrap();
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | ✖ failed | `40.0ms` |"));
+    }
+
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), &get_mock_timings(), None, 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -171,10 +737,10 @@ mod tests {
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40.0ms` | `50.0ms` ± 0.0ns<br><sub>min 50.0ms · max 50.0ms · p95 50.0ms · 0 outliers</sub> |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +749,106 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn parses_table_options() {
+        let marker = "<!--- benchmarking table overview columns=total,change slowest=2 --->";
+        assert_eq!(
+            TableOptions::parse(marker).unwrap(),
+            TableOptions {
+                name: Some("overview".into()),
+                total: true,
+                percent: false,
+                change: true,
+                slowest: 2,
+            }
+        );
+        assert_eq!(
+            TableOptions::parse(MARKER).unwrap(),
+            TableOptions::default()
+        );
+        assert!(TableOptions::parse("<!--- benchmarking table columns=speed --->").is_err());
+    }
+
+    #[test]
+    fn updates_named_tables() {
+        let overview =
+            "<!--- benchmarking table overview columns=total,percent,change slowest=1 --->";
+        let mut s = format!("{MARKER}{MARKER}\nfoo\n{overview}\n{overview}");
+        assert_eq!(find_markers(&s), vec![MARKER, overview]);
+
+        let mut previous = get_mock_timings();
+        previous[0].total_nanos = 2e+10;
+        update_content(
+            &mut s,
+            year!(2023),
+            &get_mock_timings(),
+            Some(&previous),
+            190.0,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            year!(2023),
+            &get_mock_timings(),
+            Some(&previous),
+            190.0,
+        )
+        .unwrap();
+
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches(overview).count(), 2);
+        assert_eq!(s.matches("## Benchmarks\n").count(), 1);
+        assert_eq!(s.matches("## Benchmarks (overview)\n").count(), 1);
+        assert!(s.contains("| Day | Part 1 | Part 2 | Total | % of total | Change |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2023-01.rs) | `10.0ms` | `20.0ms` | `30.0s` | 15.8% | +50.0% |"
+        ));
+        assert!(s.contains("**Total: 190.00ms** (+5.6% since the last run)"));
+        assert!(s.contains("Slowest: [Day 4](./src/bin/2023-04.rs) (`90.0s`, 47.4%)"));
+    }
+
+    #[test]
+    fn exports_csv() {
+        let mut timings = get_mock_timings();
+        timings[1].part_1 = Some(PartTiming {
+            stats: Stats::default(),
+            memory: None,
+            status: Status::Failed,
+        });
+        let previous = get_mock_timings();
+        assert_eq!(
+            to_csv(&timings[..2], Some(&previous[1..])),
+            [
+                "day,parse_ns,part_1_ns,part_2_ns,total_ns,percent,change_percent",
+                "1,,10000000,20000000,30000000000,30.00,",
+                "2,,,40000000,70000000000,70.00,0.00",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn exports_html() {
+        let html = to_html(year!(2023), &get_mock_timings(), None, 190.0, 3);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(
+            html.contains("<tr class=\"slowest\"><td>Day 4</td><td>-</td><td>40.0ms</td><td>50.0ms</td><td>90.0s</td><td>47.4%</td><td>-</td></tr>")
+        );
+        assert_eq!(html.matches("class=\"slowest\"").count(), 3);
+    }
+
+    #[test]
+    fn highlights_slowest_days_of_readme_in_html() {
+        let overview = "<!--- benchmarking table overview slowest=1 --->";
+        assert_eq!(html_slowest_days(MARKER).unwrap(), HTML_SLOWEST_DAYS);
+        assert_eq!(
+            html_slowest_days(&format!("{MARKER}{MARKER}\n{overview}{overview}")).unwrap(),
+            1
+        );
+
+        let html = to_html(year!(2023), &get_mock_timings(), None, 190.0, 1);
+        assert_eq!(html.matches("class=\"slowest\"").count(), 1);
+    }
 }
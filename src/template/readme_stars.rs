@@ -0,0 +1,245 @@
+/// Module that updates the stars table of the readme from local state, i.e. the accepted answers in
+/// `data/YYYY/answers` and the correct answers in the submission ledger.
+/// It keeps the format of the `aoc-readme-stars` action, so existing tables are replaced in place.
+use std::fmt::Display;
+use std::{fs, io};
+
+use crate::template::answers::{self, Answers};
+use crate::template::aoc_client::Verdict;
+use crate::template::readme_benchmarks::{self, locate_table};
+use crate::template::submissions::{self, Submission};
+use crate::{all_days, Day, Year};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<readme_benchmarks::Error> for Error {
+    fn from(e: readme_benchmarks::Error) -> Self {
+        match e {
+            readme_benchmarks::Error::Parser(e) => Error::Parser(e),
+            readme_benchmarks::Error::IO(e) => Error::IO(e),
+        }
+    }
+}
+
+impl From<answers::Error> for Error {
+    fn from(e: answers::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+impl From<submissions::Error> for Error {
+    fn from(e: submissions::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// The solved parts of a day.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub parts: [bool; 2],
+}
+
+impl Stars {
+    pub fn count(&self) -> usize {
+        self.parts.iter().filter(|solved| **solved).count()
+    }
+}
+
+/// The stars of all days with at least one solved part. A part counts as solved if it has an accepted answer or a
+/// correct submission.
+fn collect_stars(answers: &[(Day, Answers)], submissions: &[Submission]) -> Vec<Stars> {
+    all_days()
+        .map(|day| {
+            let mut parts = [false; 2];
+
+            if let Some((_, answers)) = answers.iter().find(|(d, _)| *d == day) {
+                for (part, solved) in (1..=2).zip(parts.iter_mut()) {
+                    *solved |= answers.get(part).is_some();
+                }
+            }
+
+            for submission in submissions
+                .iter()
+                .filter(|s| s.day == day && s.verdict == Verdict::Correct)
+            {
+                let part = usize::from(submission.part).checked_sub(1);
+                if let Some(solved) = part.and_then(|part| parts.get_mut(part)) {
+                    *solved = true;
+                }
+            }
+
+            Stars { day, parts }
+        })
+        .filter(|stars| stars.count() > 0)
+        .collect()
+}
+
+fn construct_table(year: Year, stars: &[Stars]) -> String {
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for day in stars {
+        let cells: Vec<&str> = day
+            .parts
+            .iter()
+            .map(|solved| if *solved { "⭐" } else { " " })
+            .collect();
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{year}/day/{}) | {} |",
+            day.day.into_inner(),
+            day.day.into_inner(),
+            cells.join(" | ")
+        ));
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, stars: &[Stars]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table(year, stars);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Reads the stars of a year from its answers and submission ledger.
+pub fn read(year: Year) -> Result<Vec<Stars>, Error> {
+    let answers = all_days()
+        .map(|day| Ok((day, answers::read(year, day)?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+    let submissions = submissions::read(year)?;
+    Ok(collect_stars(&answers, &submissions))
+}
+
+/// Rebuilds the stars table of the readme and returns the stars it lists.
+pub fn update(year: Year) -> Result<Vec<Stars>, Error> {
+    let stars = read(year)?;
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, &stars)?;
+    fs::write(path, &readme)?;
+    Ok(stars)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{collect_stars, update_content, Stars, MARKER};
+    use crate::template::answers::Answers;
+    use crate::template::aoc_client::Verdict;
+    use crate::template::submissions::Submission;
+    use crate::{day, year, Day};
+
+    fn submission(day: u8, part: u8, verdict: Verdict) -> Submission {
+        Submission {
+            day: Day::new(day).unwrap(),
+            part,
+            answer: "42".into(),
+            verdict,
+            timestamp: 0,
+        }
+    }
+
+    fn get_mock_stars() -> Vec<Stars> {
+        let answers = vec![
+            (
+                day!(1),
+                Answers {
+                    part_1: Some("142".into()),
+                    part_2: Some("281".into()),
+                },
+            ),
+            (
+                day!(3),
+                Answers {
+                    part_1: Some("4361".into()),
+                    part_2: None,
+                },
+            ),
+        ];
+        let submissions = vec![
+            submission(3, 2, Verdict::TooHigh),
+            submission(19, 1, Verdict::Correct),
+            submission(19, 2, Verdict::Wrong),
+        ];
+        collect_stars(&answers, &submissions)
+    }
+
+    #[test]
+    fn collects_stars() {
+        assert_eq!(
+            get_mock_stars(),
+            vec![
+                Stars {
+                    day: day!(1),
+                    parts: [true, true],
+                },
+                Stars {
+                    day: day!(3),
+                    parts: [true, false],
+                },
+                Stars {
+                    day: day!(19),
+                    parts: [true, false],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, year!(2023), &get_mock_stars()).unwrap();
+    }
+
+    #[test]
+    fn replaces_existing_table() {
+        let mut s = format!(
+            "foo\n{MARKER}\n## 2023 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |\n{MARKER}\nbar"
+        );
+        update_content(&mut s, year!(2023), &get_mock_stars()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2023/day/3) | ⭐ |   |",
+            "| [Day 19](https://adventofcode.com/2023/day/19) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
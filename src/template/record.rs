@@ -0,0 +1,210 @@
+/// Machine-readable result of running one part of a solution.
+/// Solution binaries print one record per line when invoked with `--format json`.
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::template::alloc::MemoryUsage;
+use crate::template::json::Value;
+use crate::template::stats::Stats;
+use crate::Day;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The part did not finish within the `--timeout`.
+    TimedOut,
+    /// The part panicked, see [`Record::error`].
+    Failed,
+    /// The part returned an error, see [`Record::error`].
+    Errored,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::TimedOut => "timed_out",
+            Status::Failed => "failed",
+            Status::Errored => "errored",
+        }
+    }
+
+    /// Whether the part did not finish because it panicked or returned an error.
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Failed | Status::Errored)
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "timed_out" => Ok(Status::TimedOut),
+            "failed" => Ok(Status::Failed),
+            "errored" => Ok(Status::Errored),
+            s => Err(format!("unknown status \"{s}\"")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub stats: Stats,
+    /// Only present if the solution was run with `--mem`.
+    pub memory: Option<MemoryUsage>,
+    /// Describes why a part failed, e.g. its panic message and location.
+    pub error: Option<String>,
+    pub status: Status,
+}
+
+fn nanos(value: &Value, key: &str) -> Option<Duration> {
+    value.get(key)?.as_u64().map(Duration::from_nanos)
+}
+
+impl Record {
+    pub fn to_json(&self) -> Value {
+        Value::Object(vec![
+            ("day".into(), Value::number(self.day.into_inner())),
+            ("part".into(), Value::number(self.part)),
+            (
+                "answer".into(),
+                self.answer.clone().map_or(Value::Null, Value::String),
+            ),
+            // the headline duration, i.e. the median when benchmarked.
+            (
+                "duration_ns".into(),
+                Value::number(self.stats.median.as_nanos()),
+            ),
+            ("samples".into(), Value::number(self.stats.samples)),
+            ("mean_ns".into(), Value::number(self.stats.mean.as_nanos())),
+            (
+                "stddev_ns".into(),
+                Value::number(self.stats.stddev.as_nanos()),
+            ),
+            ("min_ns".into(), Value::number(self.stats.min.as_nanos())),
+            ("max_ns".into(), Value::number(self.stats.max.as_nanos())),
+            ("p95_ns".into(), Value::number(self.stats.p95.as_nanos())),
+            ("outliers".into(), Value::number(self.stats.outliers)),
+            (
+                "allocated_bytes".into(),
+                self.memory
+                    .map_or(Value::Null, |m| Value::number(m.allocated_bytes)),
+            ),
+            (
+                "allocations".into(),
+                self.memory
+                    .map_or(Value::Null, |m| Value::number(m.allocations)),
+            ),
+            (
+                "peak_bytes".into(),
+                self.memory
+                    .map_or(Value::Null, |m| Value::number(m.peak_bytes)),
+            ),
+            (
+                "error".into(),
+                self.error.clone().map_or(Value::Null, Value::String),
+            ),
+            ("status".into(), Value::string(self.status.as_str())),
+        ])
+    }
+
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let day = value
+            .get("day")?
+            .as_u64()
+            .and_then(|d| Day::new(u8::try_from(d).ok()?))?;
+
+        Some(Self {
+            day,
+            part: u8::try_from(value.get("part")?.as_u64()?).ok()?,
+            answer: value.get("answer")?.as_str().map(Into::into),
+            stats: Stats {
+                samples: value.get("samples")?.as_u128()?,
+                mean: nanos(value, "mean_ns")?,
+                median: nanos(value, "duration_ns")?,
+                stddev: nanos(value, "stddev_ns")?,
+                min: nanos(value, "min_ns")?,
+                max: nanos(value, "max_ns")?,
+                p95: nanos(value, "p95_ns")?,
+                outliers: usize::try_from(value.get("outliers")?.as_u64()?).ok()?,
+            },
+            // the memory fields are either all present or all null.
+            memory: MemoryUsage::from_json(value),
+            error: value.get("error").and_then(Value::as_str).map(Into::into),
+            status: value.get("status")?.as_str()?.parse().ok()?,
+        })
+    }
+
+    /// Parses a line of solution output, returns [`None`] if the line is not a record.
+    pub fn parse_line(line: &str) -> Option<Self> {
+        Value::parse(line).ok().as_ref().and_then(Self::from_json)
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Record, Status};
+    use crate::day;
+    use crate::template::alloc::MemoryUsage;
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn roundtrips_records() {
+        let record = Record {
+            day: day!(8),
+            part: 2,
+            answer: Some("@ (1 samples)\n42".into()),
+            stats: Stats::from_samples(&[
+                Duration::from_nanos(74130),
+                Duration::from_nanos(74120),
+                Duration::from_nanos(90000),
+            ])
+            .unwrap(),
+            memory: Some(MemoryUsage {
+                allocated_bytes: 2048,
+                allocations: 3,
+                peak_bytes: 1024,
+            }),
+            error: None,
+            status: Status::Solved,
+        };
+        assert_eq!(Record::parse_line(&record.to_string()), Some(record));
+    }
+
+    #[test]
+    fn roundtrips_failures() {
+        let record = Record {
+            day: day!(2),
+            part: 1,
+            answer: None,
+            stats: Stats::default(),
+            memory: None,
+            error: Some("panicked at src/bin/02.rs:12:5: unknown color \"pink\"".into()),
+            status: Status::Failed,
+        };
+        assert_eq!(Record::parse_line(&record.to_string()), Some(record));
+    }
+
+    #[test]
+    fn ignores_other_lines() {
+        assert_eq!(Record::parse_line("Part 1: 42 (1.0ms)"), None);
+        assert_eq!(Record::parse_line("{\"day\": 1}"), None);
+        assert_eq!(Record::parse_line("[src/bin/01.rs:48] &line = \"x\""), None);
+    }
+}
@@ -0,0 +1,74 @@
+/// Solutions register themselves via the `solution!` macro (or by declaring an [`Entry`] for their [`Solution`]), which allows the main binary to run them in-process.
+/// With the `in_process` feature, the main binary includes every solution in `src/bin` as a module, see `build.rs`.
+use std::process;
+
+use crate::template::alloc;
+use crate::template::examples;
+use crate::template::record::Record;
+use crate::template::runner::{run_solution, RunOptions};
+use crate::template::solution::Solution;
+use crate::{Day, Year};
+
+pub struct Entry {
+    pub year: Year,
+    pub day: Day,
+    /// Runs the parse step (if any) and both parts on an input and returns their records.
+    pub run: fn(&str, &RunOptions) -> Vec<Record>,
+}
+
+impl Entry {
+    /// Creates the registry entry of a [`Solution`].
+    #[must_use]
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            run: run_solution::<S>,
+        }
+    }
+}
+
+/// Entry point of a solution binary: runs the solution against the puzzle input, or the input selected with
+/// `--input <path>`, `--stdin` or `--example [name]`. With `--examples`, it checks the examples of the day instead.
+pub fn run_main(solution: &Entry) {
+    let options = RunOptions::from_args();
+
+    if options.examples {
+        if !examples::run(solution, &options) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let input = read_input(solution.year, solution.day, &options);
+    let records = (solution.run)(&input, &options);
+
+    if records.iter().any(|record| record.status.is_failure()) {
+        process::exit(1);
+    }
+}
+
+/// Reads the input selected in `options` or exits if it cannot be read.
+pub fn read_input(year: Year, day: Day, options: &RunOptions) -> String {
+    options.input.read(year, day).unwrap_or_else(|e| {
+        eprintln!("Could not read {}: {e}", options.input.describe(year, day));
+        process::exit(1);
+    })
+}
+
+#[must_use]
+pub fn find(solutions: &[Entry], year: Year, day: Day) -> Option<&Entry> {
+    solutions
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
+
+/// Whether the current process can run solutions itself instead of spawning their binaries.
+/// This requires that it was built with the `in_process` feature and the requested profile and, for `--mem`, with
+/// the counting allocator.
+#[must_use]
+pub fn can_run_in_process(is_release: bool, is_mem: bool) -> bool {
+    cfg!(feature = "in_process")
+        && is_release == cfg!(not(debug_assertions))
+        && (!is_mem || alloc::is_enabled())
+}
@@ -1,67 +1,426 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::alloc::{self, MemoryUsage};
+use crate::template::answers;
+use crate::template::aoc_client::{self, Verdict};
+use crate::template::input::InputSource;
+use crate::template::record::{Record, Status};
+use crate::template::solution::{PartOutput, Solution};
+use crate::template::stats::Stats;
+use crate::template::submissions::{self, Submission};
+use crate::template::{OutputFormat, ANSI_ITALIC, ANSI_RESET};
+use crate::{Day, Year};
+use std::ffi::OsString;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Options that control how solution parts are run, either passed to a solution binary or set by `all`.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub format: OutputFormat,
+    /// Benchmark parts instead of running them once.
+    pub time: bool,
+    /// Measure the memory usage of parts, requires the `mem` feature.
+    pub mem: bool,
+    /// Exit the process if a part runs for longer than this.
+    pub timeout: Option<Duration>,
+    /// Submit the answer of this part to the website.
+    pub submit: Option<u8>,
+    /// Do not print anything, e.g. because the caller reports the returned records itself.
+    pub quiet: bool,
+    /// The input that a solution binary reads, see [`registry::run_main`](crate::template::registry::run_main).
+    pub input: InputSource,
+    /// Run the examples of `data/examples/NN.toml` and check their answers instead of solving the input.
+    pub examples: bool,
+}
+
+impl RunOptions {
+    /// The format to print results in, [`None`] if nothing should be printed.
+    fn output(&self) -> Option<OutputFormat> {
+        (!self.quiet).then_some(self.format)
+    }
+
+    /// Reads the options passed to a solution binary, e.g. `cargo solve 1 --time --format json`.
+    pub fn from_args() -> Self {
+        let mut raw_args: Vec<OsString> = env::args_os().skip(1).collect();
+
+        let input = InputSource::take_from_args(&mut raw_args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}.");
+            process::exit(1);
+        });
+
+        let mut args = pico_args::Arguments::from_vec(raw_args);
+
+        let options = (|| -> Result<Self, pico_args::Error> {
+            Ok(Self {
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                time: args.contains("--time"),
+                mem: args.contains("--mem"),
+                timeout: args
+                    .opt_value_from_str("--timeout")?
+                    .map(Duration::from_secs),
+                submit: args.opt_value_from_str("--submit")?,
+                quiet: false,
+                input,
+                examples: args.contains("--examples"),
+            })
+        })();
+
+        let options = options.unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}.");
+            process::exit(1);
+        });
+
+        if options.examples && options.submit.is_some() {
+            eprintln!(
+                "Unexpected command-line input: --submit cannot be combined with --examples."
+            );
+            process::exit(1);
+        }
+
+        options
+    }
+}
+
+/// Run a solution part that returns an `Option` or a `Result`, see [`PartOutput`].
+/// Parts that return an error are reported with [`Status::Errored`].
+pub fn run_part<I: Clone, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> Record {
+    let part_str = part_name(part);
+    let format = options.output();
+    let is_text = format == Some(OutputFormat::Text);
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let run = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        options,
+        |result| {
+            if is_text {
+                print_result(result, &part_str, "");
+            }
+        },
+        move |timeout| report_timeout(format, day, part, timeout),
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (result, stats, memory) = match run {
+        Ok(run) => run,
+        Err(Failure::Panicked(error)) => {
+            return report_failure(format, day, part, Status::Failed, error)
+        }
+        Err(Failure::Errored(error)) => {
+            return report_failure(format, day, part, Status::Errored, error)
+        }
+    };
+
+    let record = Record {
+        day,
+        part,
+        answer: result.as_ref().map(ToString::to_string),
+        stats,
+        memory,
+        error: None,
+        status: if result.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        },
+    };
+
+    match format {
+        Some(OutputFormat::Text) => {
+            let duration_str = format_duration(&stats) + &format_memory(memory);
+            print_result(&result, &part_str, &duration_str);
+        }
+        Some(OutputFormat::Json) => println!("{record}"),
+        None => {}
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, year, day, part, options);
     }
+
+    record
+}
+
+/// Run the shared parse step of a solution. The parsed input is returned so that both parts can borrow it.
+/// If parsing panics, neither part can run and no parsed input is returned.
+pub fn run_parse<'a, P>(
+    func: impl Fn(&'a str) -> P,
+    input: &'a str,
+    day: Day,
+    options: &RunOptions,
+) -> (Record, Option<P>) {
+    let format = options.output();
+    let is_text = format == Some(OutputFormat::Text);
+
+    let run = run_timed(
+        |input| Ok(func(input)),
+        input,
+        options,
+        |_| {
+            if is_text {
+                print_parse("");
+            }
+        },
+        move |timeout| report_timeout(format, day, 0, timeout),
+    );
+
+    let (parsed, stats, memory) = match run {
+        Ok(run) => run,
+        Err(Failure::Panicked(error) | Failure::Errored(error)) => {
+            return (report_failure(format, day, 0, Status::Failed, error), None)
+        }
+    };
+
+    let record = Record {
+        day,
+        part: 0,
+        answer: None,
+        stats,
+        memory,
+        error: None,
+        status: Status::Solved,
+    };
+
+    match format {
+        Some(OutputFormat::Text) => {
+            print_parse(&(format_duration(&stats) + &format_memory(memory)));
+        }
+        Some(OutputFormat::Json) => println!("{record}"),
+        None => {}
+    }
+
+    (record, Some(parsed))
+}
+
+/// Run a [`Solution`]: its parse step (if it has one) and both parts. Returns the records of all steps that ran.
+pub fn run_solution<S: Solution>(input: &str, options: &RunOptions) -> Vec<Record> {
+    let mut records = vec![];
+
+    let parsed = if S::HAS_PARSE_STEP {
+        let (record, parsed) = run_parse(S::parse, input, S::DAY, options);
+        records.push(record);
+        match parsed {
+            Some(parsed) => parsed,
+            None => return records,
+        }
+    } else {
+        S::parse(input)
+    };
+
+    records.push(run_part(S::part_one, &parsed, S::YEAR, S::DAY, 1, options));
+    records.push(run_part(S::part_two, &parsed, S::YEAR, S::DAY, 2, options));
+    records
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// With `--mem`, the memory usage of one additional execution is measured as well.
+///
+/// With `--timeout`, a watchdog calls `on_timeout` and exits the process if the part does not finish in time.
+///
+/// If the first execution panics or returns an error, it is returned as [`Failure`] and the part is not benched.
 fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+    func: impl Fn(I) -> Result<T, String>,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    on_timeout: impl FnOnce(Duration) + Send + 'static,
+) -> Result<(T, Stats, Option<MemoryUsage>), Failure> {
+    let show_progress = options.output() == Some(OutputFormat::Text);
     let timer = Instant::now();
-    let result = func(input.clone());
+    // the watchdog is disarmed when this is dropped at the end of the function.
+    let _watchdog = options.timeout.map(|timeout| watchdog(timeout, on_timeout));
+
+    let result = catch_panic(|| func(input.clone()))
+        .map_err(Failure::Panicked)?
+        .map_err(Failure::Errored)?;
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.time {
+        let deadline = options.timeout.map(|timeout| timer + timeout);
+        bench(&func, input.clone(), &base_time, deadline, show_progress)
+    } else {
+        Stats::single(base_time)
+    };
+
+    let memory = if options.mem {
+        measure_memory(&func, input)
     } else {
-        (base_time, 1)
+        None
     };
 
-    (result, run.0, run.1)
+    Ok((result, stats, memory))
+}
+
+/// Why the first execution of a part did not produce a result.
+enum Failure {
+    Panicked(String),
+    Errored(String),
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Runs a function and catches a panic, returning a compact description of it instead of printing it to stderr.
+pub(crate) fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    let message = Arc::new(Mutex::new(None));
+
+    let default_hook = panic::take_hook();
+    let hook_message = Arc::clone(&message);
+    panic::set_hook(Box::new(move |info| {
+        let payload = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".into());
+
+        *hook_message.lock().unwrap() = Some(match info.location() {
+            Some(location) => format!("panicked at {location}: {payload}"),
+            None => format!("panicked: {payload}"),
+        });
+    }));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    panic::set_hook(default_hook);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    result.map_err(|_| {
+        message
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| "panicked".into())
+    })
+}
+
+fn report_failure(
+    format: Option<OutputFormat>,
+    day: Day,
+    part: u8,
+    status: Status,
+    error: String,
+) -> Record {
+    let record = Record {
+        day,
+        part,
+        answer: None,
+        stats: Stats::default(),
+        memory: None,
+        error: Some(error),
+        status,
+    };
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
+    match format {
+        Some(OutputFormat::Text) => print_failure(
+            &part_name(part),
+            record.error.as_deref().unwrap_or_default(),
         ),
-    );
+        Some(OutputFormat::Json) => println!("{record}"),
+        None => {}
+    }
+
+    record
+}
+
+fn measure_memory<I, T>(func: &impl Fn(I) -> T, input: I) -> Option<MemoryUsage> {
+    if !alloc::is_enabled() {
+        eprintln!(
+            "Memory profiling requires the `mem` feature. Try running `cargo solve <day> --mem`."
+        );
+        return None;
+    }
+
+    Some(alloc::measure(|| func(input)).1)
+}
+
+/// Spawns a thread that calls `on_timeout` and exits the process unless the returned sender is dropped in time.
+fn watchdog(
+    timeout: Duration,
+    on_timeout: impl FnOnce(Duration) + Send + 'static,
+) -> mpsc::Sender<()> {
+    let (done, rx) = mpsc::channel::<()>();
+
+    thread::spawn(move || {
+        if rx.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+            on_timeout(timeout);
+            process::exit(1);
+        }
+    });
+
+    done
+}
+
+fn report_timeout(format: Option<OutputFormat>, day: Day, part: u8, timeout: Duration) {
+    match format {
+        Some(OutputFormat::Text) => {
+            print!("\r");
+            print_timeout(&part_name(part), timeout);
+        }
+        None => eprintln!("{}: timed out after {timeout:?}", part_name(part)),
+        Some(OutputFormat::Json) => {
+            let record = Record {
+                day,
+                part,
+                answer: None,
+                stats: Stats::single(timeout),
+                memory: None,
+                error: None,
+                status: Status::TimedOut,
+            };
+            println!("{record}");
+        }
+    }
+    let _ = stdout().flush();
+}
+
+/// Benchmarks a function: a short warm-up phase is followed by the measured samples.
+/// If a deadline is given, benchmarking stops early so that the part (including a `--mem` run) finishes in time.
+fn bench<I: Clone, T>(
+    func: &impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    deadline: Option<Instant>,
+    show_progress: bool,
+) -> Stats {
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+
+    let is_out_of_time =
+        || deadline.is_some_and(|deadline| Instant::now() + *base_time * 2 > deadline);
+
+    // warm up caches and the branch predictor so the first samples are not skewed.
+    for _ in 0..cmp::max(bench_iterations / 10, 1) {
+        if is_out_of_time() {
+            break;
+        }
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
+        if is_out_of_time() {
+            break;
+        }
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -69,30 +428,38 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers).unwrap_or_else(|| Stats::single(*base_time))
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+pub(crate) fn part_name(part: u8) -> String {
+    match part {
+        0 => "Parse".into(),
+        part => format!("Part {part}"),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+pub(crate) fn format_duration(stats: &Stats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ± {:.1?} @ {} samples, min {:.1?}, max {:.1?}, p95 {:.1?}, {} outliers)",
+            stats.median,
+            stats.stddev,
+            stats.samples,
+            stats.min,
+            stats.max,
+            stats.p95,
+            stats.outliers
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+pub(crate) fn format_memory(memory: Option<MemoryUsage>) -> String {
+    memory.map_or_else(String::new, |memory| format!(" [{memory}]"))
+}
+
+pub(crate) fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
@@ -127,41 +494,96 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+pub(crate) fn print_timeout(part: &str, timeout: Duration) {
+    println!("{part}: ⏱ timed out after {timeout:?}");
+}
+
+pub(crate) fn print_failure(part: &str, error: &str) {
+    println!("{part}: ✖ {error}");
+}
+
+pub(crate) fn print_parse(duration_str: &str) {
+    if duration_str.is_empty() {
+        print!("Parse:");
+    } else {
+        print!("\r");
+        println!("Parse:{duration_str}");
+    }
+}
+
+/// Try to submit one part of the solution if:
+///  1. `--submit <part>` was passed for this part.
+///  2. the solution ran against the puzzle input.
+///  3. the submission ledger does not rule the answer out.
+///  4. a session cookie is configured.
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+    options: &RunOptions,
+) -> Option<Result<Verdict, aoc_client::Error>> {
+    if options.submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if options.input != InputSource::Puzzle {
+        eprintln!(
+            "Answers can only be submitted for the puzzle input, not for {}.",
+            options.input.describe(year, day)
+        );
+        return None;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    let answer = result.to_string();
+    let ledger = submissions::read(year).unwrap_or_else(|e| {
+        eprintln!("{}: {e}", submissions::get_path_for_ledger(year));
         process::exit(1);
-    };
+    });
 
-    if part_submit != part {
+    if let Err(refusal) = submissions::check(&ledger, day, part, &answer, submissions::now()) {
+        eprintln!("Not submitting \"{answer}\": {refusal}");
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    print_progress(options, "Submitting result...");
+    let verdict = aoc_client::submit(year, day, part, &answer);
+
+    match &verdict {
+        Ok(verdict) => {
+            print_progress(options, &verdict.to_string());
+            let submission = Submission::new(day, part, &answer, verdict.clone());
+            if let Err(e) = submissions::append(year, &submission) {
+                eprintln!("Failed to record submission: {e}");
+            }
+        }
+        Err(e) => eprintln!("Failed to submit answer: {e}"),
+    }
+
+    if verdict
+        .as_ref()
+        .is_ok_and(|verdict| *verdict == Verdict::Correct)
+    {
+        match answers::record(year, day, part, &answer) {
+            Ok(()) => print_progress(
+                options,
+                &format!(
+                    "Recorded accepted answer in \"{}\".",
+                    answers::get_path_for_answers(year, day)
+                ),
+            ),
+            Err(e) => eprintln!("Failed to record accepted answer: {e}"),
+        }
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(verdict)
+}
+
+/// Prints a message about the progress of a submission. With `--format json`, stdout only carries the records, so
+/// the message goes to stderr instead.
+fn print_progress(options: &RunOptions, message: &str) {
+    match options.format {
+        OutputFormat::Text => println!("{message}"),
+        OutputFormat::Json => eprintln!("{message}"),
+    }
 }
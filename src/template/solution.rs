@@ -0,0 +1,156 @@
+/// A typed solution for one day. Solutions can implement this trait directly, or let the `solution!` macro
+/// generate an implementation from the free functions `part_one` and `part_two` of a solution file.
+use std::fmt::Display;
+
+use crate::{Day, Year};
+
+pub trait Solution {
+    /// The year of the event this solution belongs to.
+    const YEAR: Year;
+    /// The day this solution belongs to.
+    const DAY: Day;
+
+    /// Whether [`Solution::parse`] does real work. If it does, it is run (and timed) as its own step.
+    const HAS_PARSE_STEP: bool = true;
+
+    /// The parsed input that both parts receive a reference to. It may borrow from the raw input.
+    type Input<'a>;
+    /// The answer of part one: an `Option` of its value, or a `Result` if the part can fail.
+    type Answer1: PartOutput;
+    /// The answer of part two: an `Option` of its value, or a `Result` if the part can fail.
+    type Answer2: PartOutput;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+    fn part_one(input: &Self::Input<'_>) -> Self::Answer1;
+    fn part_two(input: &Self::Input<'_>) -> Self::Answer2;
+
+    /// Parses a raw input and solves part one on it. Useful in tests.
+    fn solve_part_one(input: &str) -> Self::Answer1 {
+        Self::part_one(&Self::parse(input))
+    }
+
+    /// Parses a raw input and solves part two on it. Useful in tests.
+    fn solve_part_two(input: &str) -> Self::Answer2 {
+        Self::part_two(&Self::parse(input))
+    }
+}
+
+/// Return value of a solution part.
+/// `None` means that the part is not implemented yet, an `Err` that it failed, e.g. because the input is malformed.
+pub trait PartOutput {
+    type Answer: Display;
+
+    /// Converts the output into its answer (if any) or an error message.
+    /// Errors are formatted with `{:#}`, which includes the chain of causes for error types like `anyhow::Error`.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map(Some).map_err(|e| format!("{e:#}"))
+    }
+}
+
+/// The output of a part with its answer already converted to a string, as generated by the `solution!` macro.
+pub struct FormattedOutput(pub Result<Option<String>, String>);
+
+impl FormattedOutput {
+    pub fn new(output: impl PartOutput) -> Self {
+        Self(
+            output
+                .into_answer()
+                .map(|answer| answer.map(|answer| answer.to_string())),
+        )
+    }
+}
+
+impl PartOutput for FormattedOutput {
+    type Answer = String;
+
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.0
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{PartOutput, Solution};
+    use crate::template::record::Status;
+    use crate::template::runner::{run_solution, RunOptions};
+    use crate::{day, year, Day, Year};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: Year = year!(2023);
+        const DAY: Day = day!(1);
+        type Input<'a> = Vec<&'a str>;
+        type Answer1 = Option<u32>;
+        type Answer2 = Result<u32, String>;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.lines().collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+            input.iter().map(|line| line.parse::<u32>().ok()).sum()
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Result<u32, String> {
+            input
+                .iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse::<u32>()
+                        .map_err(|e| format!("could not parse line {}: {e}", i + 1))
+                })
+                .product()
+        }
+    }
+
+    #[test]
+    fn solves_parts() {
+        assert_eq!(Sum::solve_part_one("1\n2\n3"), Some(6));
+        assert_eq!(Sum::solve_part_two("1\n2\n3"), Ok(6));
+        assert_eq!(Sum::solve_part_one("1\nx"), None);
+        assert_eq!(
+            Sum::solve_part_two("1\nx").into_answer(),
+            Err("could not parse line 2: invalid digit found in string".into())
+        );
+    }
+
+    #[test]
+    fn runs_solution() {
+        let options = RunOptions {
+            quiet: true,
+            ..RunOptions::default()
+        };
+        let records = run_solution::<Sum>("1\nx", &options);
+        let parts: Vec<_> = records
+            .iter()
+            .map(|record| (record.part, record.answer.as_deref(), record.status))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                (0, None, Status::Solved),
+                (1, None, Status::Unsolved),
+                (2, None, Status::Errored),
+            ]
+        );
+        assert_eq!(
+            records[2].error.as_deref(),
+            Some("could not parse line 2: invalid digit found in string")
+        );
+    }
+}
@@ -0,0 +1,160 @@
+/// Summary statistics over the samples taken while benchmarking a solution part.
+use std::time::Duration;
+
+use crate::template::json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub p95: Duration,
+    /// Number of samples outside of the Tukey fences (`1.5 * IQR` below Q1 or above Q3).
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Statistics for a single, unbenched execution.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            mean: duration,
+            median: duration,
+            stddev: Duration::ZERO,
+            min: duration,
+            max: duration,
+            p95: duration,
+            outliers: 0,
+        }
+    }
+
+    /// Computes statistics for a set of samples, returns [`None`] if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        #[allow(clippy::cast_precision_loss)]
+        let len = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1.0)
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Some(Self {
+            samples: nanos.len() as u128,
+            mean: from_nanos(mean),
+            median: from_nanos(percentile(&nanos, 0.5)),
+            stddev: from_nanos(variance.sqrt()),
+            min: from_nanos(nanos[0]),
+            max: from_nanos(nanos[nanos.len() - 1]),
+            p95: from_nanos(percentile(&nanos, 0.95)),
+            outliers: nanos
+                .iter()
+                .filter(|&&x| x < lower_fence || x > upper_fence)
+                .count(),
+        })
+    }
+
+    pub fn to_json(&self) -> Value {
+        Value::Object(vec![
+            ("samples".into(), Value::number(self.samples)),
+            ("mean_ns".into(), Value::number(self.mean.as_nanos())),
+            ("median_ns".into(), Value::number(self.median.as_nanos())),
+            ("stddev_ns".into(), Value::number(self.stddev.as_nanos())),
+            ("min_ns".into(), Value::number(self.min.as_nanos())),
+            ("max_ns".into(), Value::number(self.max.as_nanos())),
+            ("p95_ns".into(), Value::number(self.p95.as_nanos())),
+            ("outliers".into(), Value::number(self.outliers)),
+        ])
+    }
+
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let nanos = |key| value.get(key)?.as_u64().map(Duration::from_nanos);
+
+        Some(Self {
+            samples: value.get("samples")?.as_u128()?,
+            mean: nanos("mean_ns")?,
+            median: nanos("median_ns")?,
+            stddev: nanos("stddev_ns")?,
+            min: nanos("min_ns")?,
+            max: nanos("max_ns")?,
+            p95: nanos("p95_ns")?,
+            outliers: usize::try_from(value.get("outliers")?.as_u64()?).ok()?,
+        })
+    }
+}
+
+/// Linearly interpolated percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+fn from_nanos(nanos: f64) -> Duration {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Duration::from_nanos(nanos.max(0.0).round() as u64)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn computes_summary() {
+        let stats = Stats::from_samples(&nanos(&[50, 10, 30, 20, 40])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.stddev.as_nanos(), 16);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn median_of_even_sample_count() {
+        let stats = Stats::from_samples(&nanos(&[10, 20, 30, 40])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_nanos(11));
+        assert_eq!(stats.max, Duration::from_nanos(1000));
+    }
+
+    #[test]
+    fn handles_empty_and_single_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+        assert_eq!(
+            Stats::from_samples(&nanos(&[7])),
+            Some(Stats::single(Duration::from_nanos(7)))
+        );
+    }
+}
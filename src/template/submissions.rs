@@ -0,0 +1,356 @@
+/// Ledger of all answers submitted to the website and their verdicts, in `data/YYYY/submissions.jsonl`.
+/// It is checked before submitting, so that answers that are known to be wrong are never sent twice.
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::aoc_client::Verdict;
+use crate::template::get_data_dir;
+use crate::template::json::{self, Value};
+use crate::{Day, Year};
+
+/// The website makes you wait at least this long after a wrong answer.
+const WRONG_ANSWER_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[must_use]
+pub fn get_path_for_ledger(year: Year) -> String {
+    get_data_dir(year)
+        .join("submissions.jsonl")
+        .display()
+        .to_string()
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<json::Error> for Error {
+    fn from(e: json::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse submission ledger: {e}"),
+            Error::IO(e) => write!(f, "could not access submission ledger: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    pub fn new(day: Day, part: u8, answer: &str, verdict: Verdict) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp: now(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let (verdict, wait, message) = match &self.verdict {
+            Verdict::Correct => ("correct", None, None),
+            Verdict::Wrong => ("wrong", None, None),
+            Verdict::TooHigh => ("too_high", None, None),
+            Verdict::TooLow => ("too_low", None, None),
+            Verdict::RateLimited(wait) => ("rate_limited", *wait, None),
+            Verdict::WrongLevel => ("wrong_level", None, None),
+            Verdict::Unknown(message) => ("unknown", None, Some(message.clone())),
+        };
+
+        Value::Object(vec![
+            ("day".into(), Value::number(self.day.into_inner())),
+            ("part".into(), Value::number(self.part)),
+            ("answer".into(), Value::string(&self.answer)),
+            ("verdict".into(), Value::string(verdict)),
+            (
+                "wait_secs".into(),
+                wait.map_or(Value::Null, |wait| Value::number(wait.as_secs())),
+            ),
+            ("message".into(), message.map_or(Value::Null, Value::String)),
+            ("timestamp".into(), Value::number(self.timestamp)),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let verdict = match value.get("verdict")?.as_str()? {
+            "correct" => Verdict::Correct,
+            "wrong" => Verdict::Wrong,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "rate_limited" => Verdict::RateLimited(
+                value
+                    .get("wait_secs")
+                    .and_then(Value::as_u64)
+                    .map(Duration::from_secs),
+            ),
+            "wrong_level" => Verdict::WrongLevel,
+            "unknown" => Verdict::Unknown(
+                value
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            _ => return None,
+        };
+
+        Some(Self {
+            day: Day::new(u8::try_from(value.get("day")?.as_u64()?).ok()?)?,
+            part: u8::try_from(value.get("part")?.as_u64()?).ok()?,
+            answer: value.get("answer")?.as_str()?.to_string(),
+            verdict,
+            timestamp: value.get("timestamp")?.as_u64()?,
+        })
+    }
+
+    /// When the website accepts the next answer after this submission, in seconds since the unix epoch.
+    fn cooldown_until(&self) -> u64 {
+        let cooldown = match self.verdict {
+            Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => WRONG_ANSWER_COOLDOWN,
+            Verdict::RateLimited(wait) => wait.unwrap_or(WRONG_ANSWER_COOLDOWN),
+            _ => Duration::ZERO,
+        };
+        self.timestamp + cooldown.as_secs()
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong,
+    /// The answer is not lower than an answer that was too high.
+    TooHigh(String),
+    /// The answer is not higher than an answer that was too low.
+    TooLow(String),
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with \"{answer}\".")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already submitted and is wrong."),
+            Refusal::TooHigh(bound) => {
+                write!(f, "this answer is too high, \"{bound}\" already was.")
+            }
+            Refusal::TooLow(bound) => write!(f, "this answer is too low, \"{bound}\" already was."),
+            Refusal::Cooldown(wait) => write!(
+                f,
+                "the website does not accept answers yet, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+/// Checks an answer against the previous submissions of its part. `now` is in seconds since the unix epoch.
+pub fn check(
+    submissions: &[Submission],
+    day: Day,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<(), Refusal> {
+    let previous: Vec<&Submission> = submissions
+        .iter()
+        .filter(|submission| submission.day == day && submission.part == part)
+        .collect();
+
+    if let Some(correct) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+        return Err(Refusal::AlreadySolved(correct.answer.clone()));
+    }
+
+    let is_wrong = |s: &&&Submission| {
+        matches!(
+            s.verdict,
+            Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+        )
+    };
+
+    if previous.iter().filter(is_wrong).any(|s| s.answer == answer) {
+        return Err(Refusal::KnownWrong);
+    }
+
+    // the bounds only apply to numeric answers.
+    if let Ok(value) = answer.parse::<i128>() {
+        let bound = |verdict: Verdict| {
+            previous
+                .iter()
+                .filter(|s| s.verdict == verdict)
+                .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, &s.answer)))
+                .collect::<Vec<_>>()
+        };
+
+        if let Some((_, high)) = bound(Verdict::TooHigh)
+            .into_iter()
+            .filter(|(high, _)| value >= *high)
+            .min()
+        {
+            return Err(Refusal::TooHigh(high.clone()));
+        }
+
+        if let Some((_, low)) = bound(Verdict::TooLow)
+            .into_iter()
+            .filter(|(low, _)| value <= *low)
+            .max()
+        {
+            return Err(Refusal::TooLow(low.clone()));
+        }
+    }
+
+    // the cooldown applies to all parts.
+    let cooldown_until = submissions
+        .iter()
+        .map(Submission::cooldown_until)
+        .max()
+        .unwrap_or(0);
+
+    if cooldown_until > now {
+        return Err(Refusal::Cooldown(Duration::from_secs(cooldown_until - now)));
+    }
+
+    Ok(())
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn parse_ledger(content: &str) -> Result<Vec<Submission>, Error> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            Submission::from_json(&Value::parse(line)?)
+                .ok_or_else(|| Error::Parser(format!("malformed entry on line {}", i + 1)))
+        })
+        .collect()
+}
+
+/// Reads all submissions of a year, oldest first. A missing ledger is treated as an empty one.
+pub fn read(year: Year) -> Result<Vec<Submission>, Error> {
+    match fs::read_to_string(get_path_for_ledger(year)) {
+        Ok(content) => parse_ledger(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn append(year: Year, submission: &Submission) -> Result<(), Error> {
+    fs::create_dir_all(get_data_dir(year))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path_for_ledger(year))?;
+    writeln!(file, "{}", submission.to_json())?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{check, parse_ledger, Refusal, Submission};
+    use crate::day;
+    use crate::template::aoc_client::Verdict;
+    use std::time::Duration;
+
+    fn submission(part: u8, answer: &str, verdict: Verdict, timestamp: u64) -> Submission {
+        Submission {
+            day: day!(1),
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = vec![
+            submission(1, "142", Verdict::TooHigh, 1),
+            submission(
+                1,
+                "12",
+                Verdict::RateLimited(Some(Duration::from_secs(30))),
+                2,
+            ),
+            submission(2, "281", Verdict::Unknown("\"odd\"".into()), 3),
+        ];
+        let content: String = submissions
+            .iter()
+            .map(|submission| format!("{}\n", submission.to_json()))
+            .collect();
+        assert_eq!(parse_ledger(&content).unwrap(), submissions);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let submissions = vec![
+            submission(1, "abc", Verdict::Wrong, 0),
+            submission(1, "200", Verdict::TooHigh, 0),
+            submission(1, "300", Verdict::TooHigh, 0),
+            submission(1, "100", Verdict::TooLow, 0),
+            submission(2, "281", Verdict::Correct, 0),
+        ];
+        let check = |part, answer| check(&submissions, day!(1), part, answer, 1000);
+
+        assert_eq!(check(1, "abc"), Err(Refusal::KnownWrong));
+        assert_eq!(check(1, "250"), Err(Refusal::TooHigh("200".into())));
+        assert_eq!(check(1, "200"), Err(Refusal::KnownWrong));
+        assert_eq!(check(1, "50"), Err(Refusal::TooLow("100".into())));
+        assert_eq!(check(1, "150"), Ok(()));
+        assert_eq!(check(1, "xyz"), Ok(()));
+        assert_eq!(check(2, "282"), Err(Refusal::AlreadySolved("281".into())));
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let submissions = vec![
+            submission(1, "1", Verdict::TooLow, 100),
+            submission(
+                1,
+                "2",
+                Verdict::RateLimited(Some(Duration::from_secs(300))),
+                120,
+            ),
+        ];
+        assert_eq!(
+            check(&submissions, day!(1), 1, "5", 200),
+            Err(Refusal::Cooldown(Duration::from_secs(220)))
+        );
+        assert_eq!(check(&submissions, day!(1), 1, "5", 420), Ok(()));
+        assert_eq!(
+            check(&submissions[..1], day!(1), 2, "5", 130),
+            Err(Refusal::Cooldown(Duration::from_secs(30)))
+        );
+    }
+}
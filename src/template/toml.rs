@@ -0,0 +1,301 @@
+/// Minimal parser and writer for the subset of TOML used by the files in `data`: `key = value` pairs with string or
+/// integer values, `[name]` tables, comments and blank lines. Strings may span multiple lines if they are enclosed
+/// in `"""`. Literal strings in `'` or `'''` keep backslashes as they are, e.g. for inputs like `.|...\....`.
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A `[name]` table and its key-value pairs, in order. Pairs before the first table header belong to a table
+/// without a name.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Table {
+    pub name: Option<String>,
+    pub pairs: Vec<(String, String)>,
+}
+
+/// Parses a document without tables into its key-value pairs, in order. Integers are returned as strings.
+pub fn parse(content: &str) -> Result<Vec<(String, String)>, Error> {
+    let mut tables = parse_tables(content)?.into_iter();
+    let pairs = tables.next().map(|table| table.pairs).unwrap_or_default();
+
+    match tables.next().and_then(|table| table.name) {
+        Some(name) => Err(Error(format!("unexpected table [{name}]"))),
+        None => Ok(pairs),
+    }
+}
+
+/// Parses a document into its tables. The first table is the one without a name, even if it has no pairs.
+pub fn parse_tables(content: &str) -> Result<Vec<Table>, Error> {
+    let mut tables = vec![Table {
+        name: None,
+        pairs: vec![],
+    }];
+    let mut lines = content.lines().enumerate();
+
+    while let Some((i, line)) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = |message: &str| Error(format!("line {}: {message}", i + 1));
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .split_once(']')
+                .filter(|(_, tail)| tail.trim().is_empty() || tail.trim().starts_with('#'))
+                .map(|(name, _)| name.trim())
+                .filter(|name| is_key(name))
+                .ok_or_else(|| error("invalid table header"))?;
+
+            if tables
+                .iter()
+                .any(|table| table.name.as_deref() == Some(name))
+            {
+                return Err(error("duplicate table"));
+            }
+
+            tables.push(Table {
+                name: Some(name.to_string()),
+                pairs: vec![],
+            });
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `key = value`"))?;
+
+        let key = key.trim();
+        if !is_key(key) {
+            return Err(error("invalid key"));
+        }
+
+        let value = value.trim();
+        let mut lines = lines.by_ref().map(|(_, line)| line);
+        let value = if let Some(rest) = value.strip_prefix("\"\"\"") {
+            parse_multiline_string(rest, "\"\"\"", &mut lines).and_then(|value| unescape(&value))
+        } else if let Some(rest) = value.strip_prefix("'''") {
+            parse_multiline_string(rest, "'''", &mut lines)
+        } else {
+            parse_value(value)
+        }
+        .ok_or_else(|| error("invalid value"))?;
+
+        tables
+            .last_mut()
+            .unwrap()
+            .pairs
+            .push((key.to_string(), value));
+    }
+
+    Ok(tables)
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(rest) = value.strip_prefix('"') {
+        return parse_string(rest);
+    }
+
+    if let Some(rest) = value.strip_prefix('\'') {
+        return parse_literal_string(rest);
+    }
+
+    // an integer, optionally followed by a comment.
+    let value = value.split('#').next()?.trim();
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())).then(|| value.to_string())
+}
+
+/// Replaces the escape sequences of a basic string.
+fn unescape(value: &str) -> Option<String> {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        unescaped.push(match c {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                '"' => '"',
+                '\\' => '\\',
+                _ => return None,
+            },
+            c => c,
+        });
+    }
+
+    Some(unescaped)
+}
+
+/// Parses the remainder of a basic string after its opening quote.
+fn parse_string(rest: &str) -> Option<String> {
+    let mut chars = rest.char_indices();
+
+    let end = loop {
+        match chars.next()? {
+            (i, '"') => break i,
+            (_, '\\') => {
+                chars.next()?;
+            }
+            _ => {}
+        }
+    };
+
+    // only a comment may follow the closing quote.
+    let tail = rest[end + 1..].trim();
+    if !(tail.is_empty() || tail.starts_with('#')) {
+        return None;
+    }
+
+    unescape(&rest[..end])
+}
+
+/// Parses the remainder of a literal string after its opening quote. Its content is taken as is.
+fn parse_literal_string(rest: &str) -> Option<String> {
+    let (content, tail) = rest.split_once('\'')?;
+
+    // only a comment may follow the closing quote.
+    let tail = tail.trim();
+    if !(tail.is_empty() || tail.starts_with('#')) {
+        return None;
+    }
+
+    Some(content.to_string())
+}
+
+/// Parses the remainder of a multi-line string after its opening `quotes`, consuming lines until the closing quotes.
+/// The content is returned as is, basic strings still need to be unescaped.
+/// As in TOML, a newline directly after the opening quotes is not part of the string.
+fn parse_multiline_string<'a>(
+    rest: &str,
+    quotes: &str,
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> Option<String> {
+    let mut raw = rest.to_string();
+    if raw.trim().is_empty() {
+        raw.clear();
+    } else {
+        raw.push('\n');
+    }
+
+    let (content, tail) = loop {
+        if let Some((content, tail)) = raw.split_once(quotes) {
+            break (content.to_string(), tail.to_string());
+        }
+        raw.push_str(lines.next()?);
+        raw.push('\n');
+    };
+
+    let tail = tail.trim();
+    if !(tail.is_empty() || tail.starts_with('#')) {
+        return None;
+    }
+
+    Some(content)
+}
+
+/// Formats a string as a TOML basic string, including quotes.
+pub fn string(value: &str) -> String {
+    let mut s = String::from('"');
+    for c in value.chars() {
+        match c {
+            '\n' => s.push_str("\\n"),
+            '\t' => s.push_str("\\t"),
+            '\r' => s.push_str("\\r"),
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{parse, parse_tables, string, Table};
+
+    #[test]
+    fn parses_pairs() {
+        let content = "# answers\npart_1 = \"142\"\n\npart_2 = 281 # by hand\n";
+        assert_eq!(
+            parse(content).unwrap(),
+            vec![
+                ("part_1".to_string(), "142".to_string()),
+                ("part_2".to_string(), "281".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrips_strings() {
+        let value = "#..#\n\"a\" \\ b";
+        let content = format!("part_1 = {}", string(value));
+        assert_eq!(parse(&content).unwrap()[0].1, value);
+    }
+
+    #[test]
+    fn parses_tables() {
+        let content = "version = 1\n\n[a]\npart_1 = 142\n\n[b] # second\ninput = \"\"\"\nab\n  \\\"c\\\"\n\"\"\"\n";
+        assert_eq!(
+            parse_tables(content).unwrap(),
+            vec![
+                Table {
+                    name: None,
+                    pairs: vec![("version".to_string(), "1".to_string())]
+                },
+                Table {
+                    name: Some("a".to_string()),
+                    pairs: vec![("part_1".to_string(), "142".to_string())]
+                },
+                Table {
+                    name: Some("b".to_string()),
+                    pairs: vec![("input".to_string(), "ab\n  \"c\"\n".to_string())]
+                },
+            ]
+        );
+        assert!(parse(content).is_err());
+    }
+
+    #[test]
+    fn parses_literal_strings() {
+        let content = "[a]\npart_1 = '\\d+' # regex\ninput = '''\n.|...\\....\n|.-.\\.....\n.....|-...\n'''\n";
+        assert_eq!(
+            parse_tables(content).unwrap()[1].pairs,
+            vec![
+                ("part_1".to_string(), "\\d+".to_string()),
+                (
+                    "input".to_string(),
+                    ".|...\\....\n|.-.\\.....\n.....|-...\n".to_string()
+                ),
+            ]
+        );
+        assert!(parse("input = \"\"\"\n.|...\\....\n\"\"\"").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(parse("part_1").is_err());
+        assert!(parse("part_1 = \"unterminated").is_err());
+        assert!(parse("part 1 = 2").is_err());
+        assert!(parse("part_1 = abc").is_err());
+        assert!(parse_tables("[a\npart_1 = 1").is_err());
+        assert!(parse_tables("[a]\n[a]").is_err());
+        assert!(parse_tables("input = \"\"\"\nunterminated").is_err());
+        assert!(parse_tables("input = '''\nunterminated").is_err());
+        assert!(parse("part_1 = 'unterminated").is_err());
+    }
+}